@@ -1,14 +1,20 @@
 use std::env::current_dir;
 use std::fs::create_dir_all;
 
-use cosmwasm_schema::{export_schema, remove_schemas, schema_for};
+use cosmwasm_schema::{export_schema, export_schema_with_title, remove_schemas, schema_for};
 
-use manifesto::state::{Config, Signature, State};
+use manifesto::state::{Config, Manifesto, ManifestoVersion, Signature, State};
 
+use mars_community::events::ManifestoSignData;
 use mars_community::manifesto::{
-    ConfigResponse, ExecuteMsg, InstantiateMsg, MedalExecuteMsg, MedalMetaData, MintMsg, QueryMsg,
-    SignatureResponse, StateResponse,
+    AdminProposalResponse, ConfigResponse, EligibilityResponse, ExecuteMsg, InstantiateMsg,
+    ManifestoResponse, ManifestoVersionResponse, ManifestoVersionsResponse, ManifestosResponse,
+    MedalExecuteMsg, MedalMetaData, MedalTemplate, MedalTiersResponse, MigrateMsg, MintMsg,
+    NonceResponse, PhasesResponse, QueryMsg, SignManifestoPayload, SignatureAtHeightResponse,
+    SignatureHistogramResponse, SignatureResponse, SignaturesResponse, SigneeResponse,
+    SigneesResponse, StateResponse,
 };
+use mars_community::pause::PausedResponse;
 fn main() {
     let mut out_dir = current_dir().unwrap();
     out_dir.push("schema");
@@ -18,13 +24,34 @@ fn main() {
     export_schema(&schema_for!(InstantiateMsg), &out_dir);
     export_schema(&schema_for!(ExecuteMsg), &out_dir);
     export_schema(&schema_for!(QueryMsg), &out_dir);
-    export_schema(&schema_for!(MintMsg), &out_dir);
+    export_schema(&schema_for!(MigrateMsg), &out_dir);
+    export_schema(&schema_for!(SignManifestoPayload), &out_dir);
+    export_schema_with_title(&schema_for!(MintMsg), &out_dir, "MintMsg");
     export_schema(&schema_for!(MedalMetaData), &out_dir);
-    export_schema(&schema_for!(MedalExecuteMsg), &out_dir);
+    export_schema_with_title(&schema_for!(MedalExecuteMsg), &out_dir, "MedalExecuteMsg");
     export_schema(&schema_for!(Config), &out_dir);
+    export_schema(&schema_for!(Manifesto), &out_dir);
+    export_schema(&schema_for!(ManifestoVersion), &out_dir);
     export_schema(&schema_for!(Signature), &out_dir);
     export_schema(&schema_for!(State), &out_dir);
     export_schema(&schema_for!(ConfigResponse), &out_dir);
+    export_schema(&schema_for!(AdminProposalResponse), &out_dir);
+    export_schema(&schema_for!(ManifestoResponse), &out_dir);
+    export_schema(&schema_for!(ManifestosResponse), &out_dir);
+    export_schema(&schema_for!(ManifestoVersionResponse), &out_dir);
+    export_schema(&schema_for!(ManifestoVersionsResponse), &out_dir);
     export_schema(&schema_for!(SignatureResponse), &out_dir);
+    export_schema(&schema_for!(SignaturesResponse), &out_dir);
     export_schema(&schema_for!(StateResponse), &out_dir);
+    export_schema(&schema_for!(SignatureAtHeightResponse), &out_dir);
+    export_schema(&schema_for!(SignatureHistogramResponse), &out_dir);
+    export_schema(&schema_for!(SigneeResponse), &out_dir);
+    export_schema(&schema_for!(SigneesResponse), &out_dir);
+    export_schema(&schema_for!(NonceResponse), &out_dir);
+    export_schema(&schema_for!(PhasesResponse), &out_dir);
+    export_schema(&schema_for!(EligibilityResponse), &out_dir);
+    export_schema(&schema_for!(MedalTemplate), &out_dir);
+    export_schema(&schema_for!(MedalTiersResponse), &out_dir);
+    export_schema(&schema_for!(PausedResponse), &out_dir);
+    export_schema(&schema_for!(ManifestoSignData), &out_dir);
 }
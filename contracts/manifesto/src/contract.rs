@@ -1,14 +1,57 @@
+use bech32::{ToBase32, Variant};
 use cosmwasm_std::{
-    attr, entry_point, to_binary, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Response,
-    StdError, StdResult, WasmMsg,
+    attr, entry_point, from_slice, to_binary, to_vec, Addr, BankMsg, Binary, BlockInfo, Coin,
+    ContractResult, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Order, Reply, Response, StdError,
+    StdResult, Storage, SubMsg, SubMsgExecutionResponse, VerificationError, WasmMsg,
 };
+use cw2::{set_contract_version, ContractVersion, CONTRACT};
+use cw20::Expiration;
+use cw_storage_plus::{Bound, U32Key, U64Key};
+use semver::Version;
+use sha2::Digest;
+use std::convert::TryInto;
 
-use crate::state::{Config, Signature, State, CONFIG, METADATA, SIGNATURES, STATE};
+use crate::error::ContractError;
+use crate::migrations::{self, LEGACY_CONTRACT_VERSION, LEGACY_SIGNATURES_MIGRATED};
+use crate::state::{
+    month_key, signatures, AdminProposal, Config, Manifesto, ManifestoVersion, PendingMint,
+    Signature, State, ADMIN_PROPOSAL, CONFIG, IMPORT_FINALIZED, MANIFESTOS, MEDAL_TEMPLATES,
+    MEDAL_TIERS, MERKLE_ROOTS, METADATA, MONTH_SIGNATURES, NONCES, PENDING_MINTS, PHASE_SIGNEES,
+    SIGNEES, SIGNING_PHASES, SOL_SIGNATURES, STATE, TIER_MINTS, VERSIONS,
+};
+use mars_community::darian::{self, MartianDate, MartianTime};
+use mars_community::events::{self, ManifestoSignData, MedalMintData};
 use mars_community::manifesto::{
-    option_string_to_addr, zero_address, ConfigResponse, ExecuteMsg, InstantiateMsg,
-    MedalExecuteMsg, MedalMetaData, MintMsg, QueryMsg, SignatureResponse, StateResponse,
+    option_string_to_addr, zero_address, AdminProposalResponse, ConfigResponse,
+    EligibilityResponse, ExecuteMsg, ImportedSignature, InstantiateMsg, LegacyMedal,
+    ManifestoResponse, ManifestoVersionResponse, ManifestoVersionsResponse, ManifestosResponse,
+    MedalExecuteMsg, MedalMetaData, MedalTemplate, MedalTier, MedalTierResponse,
+    MedalTiersResponse, MigrateMsg, MintMsg, MonthCount, NonceResponse, OrderBy, PhaseResponse,
+    PhasesResponse, QueryMsg, RegionFee, SignManifestoPayload, SignatureAtHeightResponse,
+    SignatureHistogramResponse, SignatureResponse, SignaturesResponse, SigneeResponse,
+    SigneesResponse, SigningFee, SigningPhase, SolCount, StateResponse, TraitPlaceholder,
+    TraitValue,
 };
 use mars_community::metadata::{Metadata, Trait};
+use mars_community::pause::{self, PauseFlag};
+use mars_community::tax::deduct_tax;
+
+// version info for migration info
+pub const CONTRACT_NAME: &str = "crates.io:manifesto";
+pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Default max drift (in seconds) between a signee's Martian date and time and block time
+pub const DEFAULT_TIME_TOLERANCE: u64 = 300;
+
+/// Id of the replies to MEDAL mints
+const MINT_MEDAL_REPLY_ID: u64 = 1;
+
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
+/// Max number of addresses checked by `QueryMsg::AreSignees`
+const MAX_ADDRESSES: usize = 100;
+/// Max number of sols covered by `QueryMsg::SignatureHistogram`, i.e. a Darian leap year
+const MAX_HISTOGRAM_SOLS: u64 = 669;
 
 //----------------------------------------------------------------------------------------
 // Entry points
@@ -20,20 +63,17 @@ pub fn instantiate(
     _env: Env,
     _info: MessageInfo,
     msg: InstantiateMsg,
-) -> StdResult<Response> {
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
     let config = Config {
-        medal_addr: option_string_to_addr(deps.api, msg.medal_addr, zero_address())?,
-        medal_redeem_addr: option_string_to_addr(deps.api, msg.medal_redeem_addr, zero_address())?,
-        max_signees_allowed: msg.max_signees_limit,
         admin: deps.api.addr_validate(&msg.admin)?,
-    };
-
-    let state = State {
-        signees_count: 0u64,
+        time_tolerance: msg.time_tolerance.unwrap_or(DEFAULT_TIME_TOLERANCE),
+        manifestos_count: 0u64,
+        signing_fee: None,
     };
 
     CONFIG.save(deps.storage, &config)?;
-    STATE.save(deps.storage, &state)?;
 
     Ok(Response::default())
 }
@@ -41,33 +81,293 @@ pub fn instantiate(
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
-) -> Result<Response, StdError> {
+) -> Result<Response, ContractError> {
     match msg {
-        ExecuteMsg::UpdateAdmin { new_admin } => try_update_admin(deps, info, new_admin),
+        ExecuteMsg::ProposeNewAdmin { new_admin, expires } => {
+            try_propose_new_admin(deps, env, info, new_admin, expires)
+        }
+        ExecuteMsg::ClaimAdmin {} => try_claim_admin(deps, env, info),
+        ExecuteMsg::DropAdminProposal {} => try_drop_admin_proposal(deps, info),
+        ExecuteMsg::RegisterManifesto {
+            title,
+            content_hash,
+            uri,
+            max_signees_limit,
+            medal_addr,
+            medal_redeem_addr,
+        } => try_register_manifesto(
+            deps,
+            env,
+            info,
+            title,
+            content_hash,
+            uri,
+            max_signees_limit,
+            medal_addr,
+            medal_redeem_addr,
+        ),
+        ExecuteMsg::UpdateManifesto {
+            manifesto_id,
+            title,
+            max_signees_limit,
+        } => try_update_manifesto(deps, info, manifesto_id, title, max_signees_limit),
+        ExecuteMsg::AmendManifesto {
+            manifesto_id,
+            content_hash,
+            uri,
+        } => try_amend_manifesto(deps, env, info, manifesto_id, content_hash, uri),
         ExecuteMsg::UpdateMedalConfig {
+            manifesto_id,
             medal_addr,
             metadata,
-        } => try_update_medal_config(deps, info, medal_addr, metadata),
+        } => try_update_medal_config(deps, info, manifesto_id, medal_addr, metadata),
         ExecuteMsg::UpdateMedalRedeemConfig {
+            manifesto_id,
             medal_redeem_addr,
             metadata,
-        } => try_update_medal_redeem_config(deps, info, medal_redeem_addr, metadata),
+        } => try_update_medal_redeem_config(deps, info, manifesto_id, medal_redeem_addr, metadata),
+        ExecuteMsg::UpdateMedalTemplate {
+            manifesto_id,
+            template,
+        } => try_update_medal_template(deps, info, manifesto_id, template),
+        ExecuteMsg::UpdateMedalSvgTemplate {
+            manifesto_id,
+            template,
+        } => try_update_medal_svg_template(deps, info, manifesto_id, template),
+        ExecuteMsg::UpdateMedalRedemptionFee {
+            manifesto_id,
+            recipient,
+            regions,
+        } => try_update_medal_redemption_fee(deps, info, manifesto_id, recipient, regions),
+        ExecuteMsg::UpdateMedalTiers {
+            manifesto_id,
+            tiers,
+        } => try_update_medal_tiers(deps, info, manifesto_id, tiers),
+        ExecuteMsg::UpdateTimeTolerance { time_tolerance } => {
+            try_update_time_tolerance(deps, info, time_tolerance)
+        }
+        ExecuteMsg::UpdateSigningPhases {
+            manifesto_id,
+            phases,
+        } => try_update_signing_phases(deps, info, manifesto_id, phases),
+        ExecuteMsg::UpdateMerkleRoot {
+            manifesto_id,
+            round,
+            merkle_root,
+        } => try_update_merkle_root(deps, info, manifesto_id, round, merkle_root),
+        ExecuteMsg::UpdateSigningFee { treasury, amounts } => {
+            try_update_signing_fee(deps, info, treasury, amounts)
+        }
+        ExecuteMsg::UpdateGuardian { guardian } => try_update_guardian(deps, info, guardian),
+        ExecuteMsg::Pause { flags } => try_pause(deps, info, flags),
+        ExecuteMsg::Unpause { flags } => try_unpause(deps, info, flags),
+        ExecuteMsg::WithdrawSignature { manifesto_id } => {
+            try_withdraw_signature(deps, env, info, manifesto_id)
+        }
         ExecuteMsg::SignManifesto {
+            manifesto_id,
+            content_hash,
+            martian_date,
+            martian_time,
+            proof,
+        } => try_sign_manifesto(
+            deps,
+            env,
+            info.sender,
+            info.funds,
+            manifesto_id,
+            content_hash,
+            martian_date,
+            martian_time,
+            proof,
+        ),
+        ExecuteMsg::SignManifestoFor {
+            manifesto_id,
+            content_hash,
+            signee_pubkey,
+            signature,
+            martian_date,
+            martian_time,
+            nonce,
+            proof,
+        } => try_sign_manifesto_for(
+            deps,
+            env,
+            info,
+            manifesto_id,
+            content_hash,
+            signee_pubkey,
+            signature,
             martian_date,
             martian_time,
-        } => try_sign_manifesto(deps, info, martian_date, martian_time),
+            nonce,
+            proof,
+        ),
+        ExecuteMsg::RetryMedalMint { manifesto_id } => {
+            try_retry_medal_mint(deps, info, manifesto_id)
+        }
+        ExecuteMsg::ImportSignatures {
+            manifesto_id,
+            entries,
+            mint_medals,
+        } => try_import_signatures(deps, env, info, manifesto_id, entries, mint_medals),
+        ExecuteMsg::FinalizeImport {} => try_finalize_import(deps, info),
+        ExecuteMsg::MigrateLegacySignatures { entries } => {
+            try_migrate_legacy_signatures(deps, env, info, entries)
+        }
     }
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
-    match msg {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
+    let response = match msg {
         QueryMsg::Config {} => to_binary(&query_config(deps)?),
-        QueryMsg::State {} => to_binary(&query_state(deps)?),
-        QueryMsg::GetSignature { signee } => to_binary(&get_signature(deps, signee)?),
+        QueryMsg::AdminProposal {} => to_binary(&query_admin_proposal(deps)?),
+        QueryMsg::Manifesto { manifesto_id } => to_binary(&query_manifesto(deps, manifesto_id)?),
+        QueryMsg::Manifestos { start_after, limit } => {
+            to_binary(&query_manifestos(deps, start_after, limit)?)
+        }
+        QueryMsg::ManifestoVersion {
+            manifesto_id,
+            version,
+        } => to_binary(&query_manifesto_version(deps, manifesto_id, version)?),
+        QueryMsg::ManifestoVersions {
+            manifesto_id,
+            start_after,
+            limit,
+        } => to_binary(&query_manifesto_versions(
+            deps,
+            manifesto_id,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::MedalTemplate { manifesto_id } => {
+            to_binary(&query_medal_template(deps, manifesto_id)?)
+        }
+        QueryMsg::MedalTiers { manifesto_id } => to_binary(&query_medal_tiers(deps, manifesto_id)?),
+        QueryMsg::State { manifesto_id } => to_binary(&query_state(deps, manifesto_id)?),
+        QueryMsg::StateAtHeight {
+            manifesto_id,
+            height,
+        } => to_binary(&query_state_at_height(deps, manifesto_id, height)?),
+        QueryMsg::GetSignature {
+            manifesto_id,
+            signee,
+        } => to_binary(&get_signature(deps, manifesto_id, signee)?),
+        QueryMsg::SignatureByOrdinal {
+            manifesto_id,
+            ordinal,
+        } => to_binary(&query_signature_by_ordinal(deps, manifesto_id, ordinal)?),
+        QueryMsg::SignaturesByDate {
+            manifesto_id,
+            date,
+            start_after,
+            limit,
+        } => to_binary(&query_signatures_by_date(
+            deps,
+            manifesto_id,
+            date,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::SignatureHistogram {
+            manifesto_id,
+            from,
+            to,
+        } => to_binary(&query_signature_histogram(deps, manifesto_id, from, to)?),
+        QueryMsg::IsSignee {
+            manifesto_id,
+            address,
+        } => to_binary(&query_is_signee(deps, manifesto_id, address)?),
+        QueryMsg::AreSignees {
+            manifesto_id,
+            addresses,
+        } => to_binary(&query_are_signees(deps, manifesto_id, addresses)?),
+        QueryMsg::SignatureAtHeight {
+            manifesto_id,
+            signee,
+            height,
+        } => to_binary(&query_signature_at_height(
+            deps,
+            manifesto_id,
+            signee,
+            height,
+        )?),
+        QueryMsg::AllSignatures {
+            manifesto_id,
+            start_after,
+            limit,
+            order,
+        } => to_binary(&query_all_signatures(
+            deps,
+            manifesto_id,
+            start_after,
+            limit,
+            order,
+        )?),
+        QueryMsg::Nonce { address } => to_binary(&query_nonce(deps, address)?),
+        QueryMsg::Phases { manifesto_id } => to_binary(&query_phases(deps, env, manifesto_id)?),
+        QueryMsg::IsEligible {
+            manifesto_id,
+            address,
+            proof,
+            round,
+        } => to_binary(&query_is_eligible(
+            deps,
+            env,
+            manifesto_id,
+            address,
+            proof,
+            round,
+        )?),
+        QueryMsg::Paused {} => to_binary(&pause::query_paused(deps.storage)?),
+    }?;
+
+    Ok(response)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    // v1.0.0 did not store its version with cw2
+    let previous = CONTRACT
+        .may_load(deps.storage)?
+        .unwrap_or_else(|| ContractVersion {
+            contract: CONTRACT_NAME.to_string(),
+            version: LEGACY_CONTRACT_VERSION.to_string(),
+        });
+    if previous.contract != CONTRACT_NAME {
+        return Err(ContractError::ContractNameMismatch {
+            contract: previous.contract,
+        });
+    }
+    let previous_version = parse_version(&previous.version)?;
+    if previous_version > parse_version(CONTRACT_VERSION)? {
+        return Err(ContractError::CannotDowngrade {
+            previous_version: previous.version,
+            new_version: CONTRACT_VERSION.to_string(),
+        });
+    }
+    if let Some(content_hash) = &msg.content_hash {
+        decode_hash(content_hash)?;
+    }
+
+    migrations::migrate(deps.storage, &env, &previous_version, msg)?;
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "migrate")
+        .add_attribute("from_version", previous.version)
+        .add_attribute("to_version", CONTRACT_VERSION))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg.id {
+        MINT_MEDAL_REPLY_ID => handle_medal_mint_reply(deps, msg.result),
+        id => Err(ContractError::UnknownReplyId { id }),
     }
 }
 
@@ -75,273 +375,2129 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
 // Handle functions
 //----------------------------------------------------------------------------------------
 
-/// @dev Admin function to update the admin
-/// @param new_admin : New admin
-pub fn try_update_admin(
+/// @dev Admin function to propose a new admin, who then has to claim the role
+/// @param new_admin : Proposed admin
+/// @param expires : Expiration of the proposal
+pub fn try_propose_new_admin(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     new_admin: String,
-) -> StdResult<Response> {
+    expires: Expiration,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // Verify if called by Admin
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if expires.is_expired(&env.block) {
+        return Err(ContractError::AdminProposalAlreadyExpired {});
+    }
+
+    let proposal = AdminProposal {
+        new_admin: deps.api.addr_validate(&new_admin)?,
+        expires,
+    };
+    ADMIN_PROPOSAL.save(deps.storage, &proposal)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "propose_new_admin"),
+        attr("new_admin", new_admin),
+        attr("expires", proposal.expires.to_string()),
+    ]))
+}
+
+/// @dev Accepts the admin role. Must be called by the proposed admin before the proposal expires
+pub fn try_claim_admin(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    let proposal = ADMIN_PROPOSAL
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoAdminProposal {})?;
+
+    // Verify if called by the proposed admin
+    if info.sender != proposal.new_admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if proposal.expires.is_expired(&env.block) {
+        return Err(ContractError::AdminProposalExpired {});
+    }
+
+    let previous_admin = config.admin;
+    config.admin = proposal.new_admin;
+    CONFIG.save(deps.storage, &config)?;
+    ADMIN_PROPOSAL.remove(deps.storage);
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "claim_admin"),
+        attr("previous_admin", previous_admin),
+        attr("new_admin", config.admin),
+    ]))
+}
+
+/// @dev Admin function to cancel the pending admin proposal
+pub fn try_drop_admin_proposal(
+    deps: DepsMut,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // Verify if called by Admin
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    ADMIN_PROPOSAL.remove(deps.storage);
+
+    Ok(Response::new().add_attribute("action", "drop_admin_proposal"))
+}
+
+/// @dev Admin function to register a new manifesto
+/// @param title : Title of the manifesto
+/// @param content_hash : Hex encoded SHA-256 hash of the manifesto text
+/// @param uri : (Optional) Where the manifesto text can be found
+/// @param max_signees_limit : Max number of signees allowed to sign the manifesto
+/// @param medal_addr : (Optional) MEDAL token address
+/// @param medal_redeem_addr : (Optional) MEDAL (Redeem) token address
+pub fn try_register_manifesto(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    title: String,
+    content_hash: String,
+    uri: Option<String>,
+    max_signees_limit: u64,
+    medal_addr: Option<String>,
+    medal_redeem_addr: Option<String>,
+) -> Result<Response, ContractError> {
     let mut config = CONFIG.load(deps.storage)?;
 
     // Verify if called by Admin
     if info.sender != config.admin {
-        return Err(StdError::generic_err("Unauthorized"));
+        return Err(ContractError::Unauthorized {});
     }
 
-    config.admin = info.sender;
+    decode_hash(&content_hash)?;
+
+    config.manifestos_count += 1;
+    let manifesto = Manifesto {
+        id: config.manifestos_count,
+        title,
+        version: 1,
+        max_signees_allowed: max_signees_limit,
+        medal_addr: option_string_to_addr(deps.api, medal_addr, zero_address())?,
+        medal_redeem_addr: option_string_to_addr(deps.api, medal_redeem_addr, zero_address())?,
+    };
+
+    // Update & Save
     CONFIG.save(deps.storage, &config)?;
+    MANIFESTOS.save(deps.storage, U64Key::new(manifesto.id), &manifesto)?;
+    VERSIONS.save(
+        deps.storage,
+        (U64Key::new(manifesto.id), U32Key::new(manifesto.version)),
+        &ManifestoVersion {
+            version: manifesto.version,
+            content_hash: content_hash.clone(),
+            uri,
+            published_at: env.block.time,
+        },
+    )?;
+    STATE.save(
+        deps.storage,
+        U64Key::new(manifesto.id),
+        &State {
+            signees_count: 0u64,
+            withdrawn_count: 0u64,
+        },
+        env.block.height,
+    )?;
 
     Ok(Response::new().add_attributes(vec![
-        attr("action", "update_admin"),
-        attr("new_admin", new_admin),
+        attr("action", "register_manifesto"),
+        attr("manifesto_id", manifesto.id.to_string()),
+        attr("content_hash", content_hash),
+    ]))
+}
+
+/// @dev Admin function to update the title and signee limit of a manifesto
+/// @param manifesto_id : Manifesto to update
+/// @param title : (Optional) New title
+/// @param max_signees_limit : (Optional) New max number of signees
+pub fn try_update_manifesto(
+    deps: DepsMut,
+    info: MessageInfo,
+    manifesto_id: u64,
+    title: Option<String>,
+    max_signees_limit: Option<u64>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // Verify if called by Admin
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut manifesto = load_manifesto(deps.as_ref(), manifesto_id)?;
+    if let Some(title) = title {
+        manifesto.title = title;
+    }
+    if let Some(max_signees_limit) = max_signees_limit {
+        manifesto.max_signees_allowed = max_signees_limit;
+    }
+    MANIFESTOS.save(deps.storage, U64Key::new(manifesto_id), &manifesto)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "update_manifesto"),
+        attr("manifesto_id", manifesto_id.to_string()),
+    ]))
+}
+
+/// @dev Admin function to publish a new version of the manifesto text
+/// @param manifesto_id : Manifesto to amend
+/// @param content_hash : Hex encoded SHA-256 hash of the amended text
+/// @param uri : (Optional) Where the amended text can be found
+pub fn try_amend_manifesto(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    manifesto_id: u64,
+    content_hash: String,
+    uri: Option<String>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // Verify if called by Admin
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    decode_hash(&content_hash)?;
+
+    let mut manifesto = load_manifesto(deps.as_ref(), manifesto_id)?;
+    manifesto.version += 1;
+
+    // Update & Save
+    MANIFESTOS.save(deps.storage, U64Key::new(manifesto_id), &manifesto)?;
+    VERSIONS.save(
+        deps.storage,
+        (U64Key::new(manifesto_id), U32Key::new(manifesto.version)),
+        &ManifestoVersion {
+            version: manifesto.version,
+            content_hash: content_hash.clone(),
+            uri,
+            published_at: env.block.time,
+        },
+    )?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "amend_manifesto"),
+        attr("manifesto_id", manifesto_id.to_string()),
+        attr("version", manifesto.version.to_string()),
+        attr("content_hash", content_hash),
     ]))
 }
 
-/// @dev Admin function to update MEDAL NFT Configuration
+/// @dev Admin function to update MEDAL NFT Configuration of a manifesto
+/// @param manifesto_id : Manifesto the MEDAL is minted for
 /// @param medal_addr : New MEDAL Token Address
 pub fn try_update_medal_config(
     deps: DepsMut,
     info: MessageInfo,
+    manifesto_id: u64,
     medal_addr: String,
     metadata: Metadata,
-) -> StdResult<Response> {
-    let mut config = CONFIG.load(deps.storage)?;
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
 
     // Verify if called by Admin
     if info.sender != config.admin {
-        return Err(StdError::generic_err("Unauthorized"));
+        return Err(ContractError::Unauthorized {});
     }
 
+    let mut manifesto = load_manifesto(deps.as_ref(), manifesto_id)?;
+
     let medal_metadata = MedalMetaData {
-        name_prefix: metadata.name.unwrap_or_else(|| "".to_string()),
-        description: metadata.description.unwrap_or_else(|| "".to_string()),
-        image: metadata.image.unwrap_or_else(|| "".to_string()),
-        token_uri: metadata.external_url.unwrap_or_else(|| "".to_string()),
+        name_prefix: metadata.name.unwrap_or_default(),
+        description: metadata.description.unwrap_or_default(),
+        image: metadata.image.unwrap_or_default(),
+        token_uri: metadata.external_url.unwrap_or_default(),
     };
 
     // Update & Save
-    config.medal_addr = deps.api.addr_validate(&medal_addr)?;
-    CONFIG.save(deps.storage, &config)?;
-    METADATA.save(deps.storage, medal_addr.as_bytes(), &medal_metadata)?;
+    manifesto.medal_addr = deps.api.addr_validate(&medal_addr)?;
+    MANIFESTOS.save(deps.storage, U64Key::new(manifesto_id), &manifesto)?;
+    METADATA.save(deps.storage, U64Key::new(manifesto_id), &medal_metadata)?;
 
     Ok(Response::new().add_attributes(vec![
         attr("action", "update_medal_config"),
+        attr("manifesto_id", manifesto_id.to_string()),
         attr("medal_addr", medal_addr),
     ]))
 }
 
-/// @dev Admin function to update MEDAL (Redeem) NFT Configuration
+/// @dev Admin function to update MEDAL (Redeem) NFT Configuration of a manifesto
+/// @param manifesto_id : Manifesto the MEDAL is minted for
 /// @param medal_redeem_addr : New MEDAL (Redeem) token address
 pub fn try_update_medal_redeem_config(
     deps: DepsMut,
     info: MessageInfo,
+    manifesto_id: u64,
     medal_redeem_addr: String,
     metadata: MedalMetaData,
-) -> StdResult<Response> {
-    let mut config = CONFIG.load(deps.storage)?;
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
 
     // Verify if called by Admin
     if info.sender != config.admin {
-        return Err(StdError::generic_err("Unauthorized"));
+        return Err(ContractError::Unauthorized {});
     }
 
+    let mut manifesto = load_manifesto(deps.as_ref(), manifesto_id)?;
     let cosmos_msg = build_update_medal_redeem_addr_msg(
-        config.medal_addr.to_string(),
+        manifesto.medal_addr.to_string(),
         medal_redeem_addr.clone(),
         metadata,
     )?;
 
     // Update & Save
-    config.medal_redeem_addr = deps.api.addr_validate(&medal_redeem_addr)?;
-    CONFIG.save(deps.storage, &config)?;
+    manifesto.medal_redeem_addr = deps.api.addr_validate(&medal_redeem_addr)?;
+    MANIFESTOS.save(deps.storage, U64Key::new(manifesto_id), &manifesto)?;
 
     Ok(Response::new().add_message(cosmos_msg).add_attributes(vec![
         attr("action", "update_medal_redeem_config"),
+        attr("manifesto_id", manifesto_id.to_string()),
         attr("medal_redeem_addr", medal_redeem_addr),
     ]))
 }
 
-/// @dev Stores signature details provided by the Signee. https://manifesto.marsprotocol.io/ : Web app to facilitate signing with Martian Date and Time
-/// @param martian_date : An equivalent martian date as according to th Darian Calender
-/// @param martian_time : Coordinated Martian Time is like UTC but for Mars
-pub fn try_sign_manifesto(
+/// @dev Admin function to set the template of the traits of MEDALs minted from now on
+/// @param manifesto_id : Manifesto the MEDAL is minted for
+/// @param template : (Optional) New template, the default one (Martian date and time) if None
+pub fn try_update_medal_template(
     deps: DepsMut,
     info: MessageInfo,
-    martian_date: String,
-    martian_time: String,
-) -> StdResult<Response> {
+    manifesto_id: u64,
+    template: Option<MedalTemplate>,
+) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
-    let mut state = STATE.load(deps.storage)?;
-    let signee = info.sender;
 
-    // Verify if Time is in the valid format
-    if !is_valid_time(&martian_time) {
-        return Err(StdError::generic_err("Invalid Martian Time"));
+    // Verify if called by Admin
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
     }
 
-    // Verify if Date is in the valid format
-    if !is_valid_date(&martian_date) {
-        return Err(StdError::generic_err("Invalid Martian Date"));
+    load_manifesto(deps.as_ref(), manifesto_id)?;
+
+    match template {
+        Some(template) => {
+            // Verify if the background color is a hex color, as expected by OpenSea
+            if let Some(color) = &template.background_color {
+                if color.len() != 6 || !color.chars().all(|c| c.is_ascii_hexdigit()) {
+                    return Err(ContractError::InvalidBackgroundColor {
+                        color: color.clone(),
+                    });
+                }
+            }
+            // Verify if the traits the MEDAL contract renders SVG images from are kept
+            if let Some(trait_type) = template.missing_svg_trait() {
+                return Err(ContractError::MissingSvgTrait {
+                    trait_type: trait_type.to_string(),
+                });
+            }
+            MEDAL_TEMPLATES.save(deps.storage, U64Key::new(manifesto_id), &template)?;
+        }
+        None => MEDAL_TEMPLATES.remove(deps.storage, U64Key::new(manifesto_id)),
     }
 
-    // Verify if signee limit is not reached yet
-    if state.signees_count >= config.max_signees_allowed {
-        return Err(StdError::generic_err("Max signee limit reached"));
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "update_medal_template"),
+        attr("manifesto_id", manifesto_id.to_string()),
+    ]))
+}
+
+/// @dev Admin function to set the SVG template the MEDAL contract of a manifesto renders its MEDALs from
+/// @param manifesto_id : Manifesto the MEDAL is minted for
+/// @param template : (Optional) SVG template, MEDALs are not rendered on-chain if None
+pub fn try_update_medal_svg_template(
+    deps: DepsMut,
+    info: MessageInfo,
+    manifesto_id: u64,
+    template: Option<String>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // Verify if called by Admin
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
     }
 
-    // Make sure the account has not already signed the Manifesto
-    let signature_ = SIGNATURES
-        .may_load(deps.storage, signee.to_string().as_bytes())?
-        .unwrap_or_default();
+    let manifesto = load_manifesto(deps.as_ref(), manifesto_id)?;
+    let cosmos_msg =
+        build_update_medal_svg_template_msg(manifesto.medal_addr.to_string(), template)?;
 
-    if signature_.signee == signee {
-        return Err(StdError::generic_err(
-            "User has already signed the Manifesto",
-        ));
+    Ok(Response::new().add_message(cosmos_msg).add_attributes(vec![
+        attr("action", "update_medal_svg_template"),
+        attr("manifesto_id", manifesto_id.to_string()),
+    ]))
+}
+
+/// @dev Admin function to set the fee the MEDAL contract of a manifesto charges to redeem a MEDAL for a physical pin
+/// @param manifesto_id : Manifesto the MEDAL is minted for
+/// @param recipient : Address the redemption fees are forwarded to
+/// @param regions : Shipping regions with the accepted denoms and amounts of each. Redemption is free if empty
+pub fn try_update_medal_redemption_fee(
+    deps: DepsMut,
+    info: MessageInfo,
+    manifesto_id: u64,
+    recipient: String,
+    regions: Vec<RegionFee>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // Verify if called by Admin
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
     }
 
-    let token_id = state.signees_count + 1;
-    let medal_mint_msg = build_medal_mint_msg(
-        deps.as_ref(),
-        config.medal_addr.to_string(),
-        token_id,
-        signee.to_string(),
-        martian_date.clone(),
-        martian_time.clone(),
+    let manifesto = load_manifesto(deps.as_ref(), manifesto_id)?;
+    let cosmos_msg = build_update_medal_redemption_fee_msg(
+        manifesto.medal_addr.to_string(),
+        recipient,
+        regions,
     )?;
 
-    state.signees_count += 1;
-    let signature_ = Signature {
-        signee: signee.clone(),
-        martian_date,
-        martian_time,
-    };
+    Ok(Response::new().add_message(cosmos_msg).add_attributes(vec![
+        attr("action", "update_medal_redemption_fee"),
+        attr("manifesto_id", manifesto_id.to_string()),
+    ]))
+}
+
+/// @dev Admin function to replace the MEDAL tiers of a manifesto
+/// @param manifesto_id : Manifesto the MEDAL is minted for
+/// @param tiers : Ordinal ranges with their own MEDAL metadata. MEDALs outside of any tier use the metadata of the manifesto
+pub fn try_update_medal_tiers(
+    deps: DepsMut,
+    info: MessageInfo,
+    manifesto_id: u64,
+    mut tiers: Vec<MedalTier>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
 
-    STATE.save(deps.storage, &state)?;
-    SIGNATURES.save(deps.storage, signee.to_string().as_bytes(), &signature_)?;
+    // Verify if called by Admin
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
 
-    Ok(Response::new()
-        .add_message(medal_mint_msg)
-        .add_attributes(vec![
-            attr("action", "sign_manifesto"),
-            attr("signee", signee),
-            attr("signee_count", state.signees_count.to_string()),
-        ]))
-}
+    load_manifesto(deps.as_ref(), manifesto_id)?;
 
-//----------------------------------------------------------------------------------------
-// Query functions
-//----------------------------------------------------------------------------------------
+    // Verify if tiers have unique names and disjoint, non-empty ordinal ranges
+    tiers.sort_by_key(|tier| tier.first_ordinal);
+    for (i, tier) in tiers.iter().enumerate() {
+        if tier.first_ordinal == 0 || tier.last_ordinal < tier.first_ordinal {
+            return Err(ContractError::InvalidTierRange {
+                tier: tier.name.clone(),
+            });
+        }
+        if tiers[..i].iter().any(|t| t.name == tier.name) {
+            return Err(ContractError::DuplicateTierName {
+                tier: tier.name.clone(),
+            });
+        }
+        if i > 0 && tiers[i - 1].last_ordinal >= tier.first_ordinal {
+            return Err(ContractError::OverlappingTiers {
+                tier: tier.name.clone(),
+            });
+        }
+    }
 
-/// @dev Returns the Medal token address and max signee's allowed to sign the Manifesto
-fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
-    let config = CONFIG.load(deps.storage)?;
+    MEDAL_TIERS.save(deps.storage, U64Key::new(manifesto_id), &tiers)?;
 
-    Ok(ConfigResponse {
-        medal_addr: config.medal_addr,
-        max_signees_allowed: config.max_signees_allowed,
-        admin: config.admin.to_string(),
-    })
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "update_medal_tiers"),
+        attr("manifesto_id", manifesto_id.to_string()),
+        attr("tiers_count", tiers.len().to_string()),
+    ]))
 }
 
-/// @dev Returns the total number of Signee's that have signed the Manifesto
-fn query_state(deps: Deps) -> StdResult<StateResponse> {
-    let state = STATE.load(deps.storage)?;
+/// @dev Admin function to update the max drift allowed between the Martian date and time sent by a signee and block time
+/// @param time_tolerance : New tolerance in seconds
+pub fn try_update_time_tolerance(
+    deps: DepsMut,
+    info: MessageInfo,
+    time_tolerance: u64,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
 
-    Ok(StateResponse {
-        signee_count: state.signees_count,
-    })
-}
+    // Verify if called by Admin
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
 
-/// @dev Returns Signauture details of the signee
-fn get_signature(deps: Deps, signee: String) -> StdResult<SignatureResponse> {
-    let signature_ = SIGNATURES.load(deps.storage, signee.as_bytes())?;
-    Ok(SignatureResponse {
-        signee: signature_.signee.to_string(),
-        martian_date: signature_.martian_date,
-        martian_time: signature_.martian_time,
-    })
+    config.time_tolerance = time_tolerance;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "update_time_tolerance"),
+        attr("time_tolerance", time_tolerance.to_string()),
+    ]))
 }
 
-//----------------------------------------------------------------------------------------
-// Helper functions
-//----------------------------------------------------------------------------------------
+/// @dev Admin function to update the signing phases of a manifesto
+/// @param manifesto_id : Manifesto the phases apply to
+/// @param phases : New signing phases. Signing is open at all times if empty
+pub fn try_update_signing_phases(
+    deps: DepsMut,
+    info: MessageInfo,
+    manifesto_id: u64,
+    phases: Vec<SigningPhase>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
 
-fn is_valid_time(time: &str) -> bool {
-    let bytes = time.as_bytes();
-    if bytes.len() != 12 {
-        return false;
+    // Verify if called by Admin
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
     }
-    true
-}
 
-fn is_valid_date(date: &str) -> bool {
-    let bytes = date.as_bytes();
-    if bytes.len() < 12 || bytes.len() > 24 {
-        return false;
+    load_manifesto(deps.as_ref(), manifesto_id)?;
+
+    // Verify if phase ids are unique and windows end after they start
+    for (i, phase) in phases.iter().enumerate() {
+        if phases[..i].iter().any(|p| p.id == phase.id) {
+            return Err(ContractError::DuplicatePhaseId { phase_id: phase.id });
+        }
+        if !phase.has_valid_window() {
+            return Err(ContractError::InvalidPhaseWindow { phase_id: phase.id });
+        }
     }
-    true
+
+    SIGNING_PHASES.save(deps.storage, U64Key::new(manifesto_id), &phases)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "update_signing_phases"),
+        attr("manifesto_id", manifesto_id.to_string()),
+        attr("phases_count", phases.len().to_string()),
+    ]))
 }
 
-/// Helper Function. Returns CosmosMsg which updates MEDAL (Redeem) address in the MEDAL Contract
-pub fn build_update_medal_redeem_addr_msg(
-    medal_addr: String,
-    medal_redeem_addr: String,
-    metadata: MedalMetaData,
-) -> StdResult<CosmosMsg> {
-    Ok(CosmosMsg::Wasm(WasmMsg::Execute {
-        contract_addr: medal_addr,
-        msg: to_binary(&MedalExecuteMsg::UpdateMedalRedeemConfig {
-            medal_redeem_addr,
-            metadata,
-        })?,
-        funds: vec![],
-    }))
+/// @dev Admin function to set the allowlist of a signing round of a manifesto
+/// @param manifesto_id : Manifesto the allowlist applies to
+/// @param round : Signing phase id, or 0 if the manifesto has no signing phases
+/// @param merkle_root : Hex encoded Merkle root of allowed addresses. Signing is open to everyone if None
+pub fn try_update_merkle_root(
+    deps: DepsMut,
+    info: MessageInfo,
+    manifesto_id: u64,
+    round: u32,
+    merkle_root: Option<String>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // Verify if called by Admin
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    load_manifesto(deps.as_ref(), manifesto_id)?;
+    let key = (U64Key::new(manifesto_id), U32Key::new(round));
+    match &merkle_root {
+        Some(root) => {
+            decode_hash(root)?;
+            MERKLE_ROOTS.save(deps.storage, key, root)?;
+        }
+        None => MERKLE_ROOTS.remove(deps.storage, key),
+    }
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "update_merkle_root"),
+        attr("manifesto_id", manifesto_id.to_string()),
+        attr("round", round.to_string()),
+        attr("merkle_root", merkle_root.unwrap_or_default()),
+    ]))
 }
 
-/// Helper Function. Returns CosmosMsg which updates MEDAL (Redeem) address in the MEDAL Contract
-pub fn build_medal_mint_msg(
-    deps: Deps,
-    medal_addr: String,
-    token_id: u64,
-    user_addr: String,
-    martian_date: String,
-    martian_time: String,
-) -> StdResult<CosmosMsg> {
-    let metadata = METADATA.load(deps.storage, medal_addr.as_bytes())?;
+/// @dev Admin function to set the fee charged to new signees
+/// @param treasury : Address the fees are forwarded to
+/// @param amounts : Accepted denoms with the amount to pay in each of them. Signing is free if empty
+pub fn try_update_signing_fee(
+    deps: DepsMut,
+    info: MessageInfo,
+    treasury: String,
+    amounts: Vec<Coin>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
 
-    let mut attributes_vec = vec![];
-    let date_attribute = Trait {
-        display_type: None,
-        trait_type: "martian_date".to_string(),
-        value: martian_date,
-    };
-    attributes_vec.push(date_attribute);
+    // Verify if called by Admin
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // Verify if amounts are positive and denoms are unique
+    for (i, coin) in amounts.iter().enumerate() {
+        if coin.amount.is_zero() {
+            return Err(ContractError::ZeroFeeAmount {
+                denom: coin.denom.clone(),
+            });
+        }
+        if amounts[..i].iter().any(|c| c.denom == coin.denom) {
+            return Err(ContractError::DuplicateFeeDenom {
+                denom: coin.denom.clone(),
+            });
+        }
+    }
 
-    let time_attribute = Trait {
-        display_type: None,
-        trait_type: "martian_time".to_string(),
-        value: martian_time,
+    let treasury = deps.api.addr_validate(&treasury)?;
+    config.signing_fee = if amounts.is_empty() {
+        None
+    } else {
+        Some(SigningFee { treasury, amounts })
     };
-    attributes_vec.push(time_attribute);
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "update_signing_fee"),
+        attr(
+            "amounts",
+            config
+                .signing_fee
+                .map(|fee| {
+                    fee.amounts
+                        .iter()
+                        .map(Coin::to_string)
+                        .collect::<Vec<_>>()
+                        .join(",")
+                })
+                .unwrap_or_default(),
+        ),
+    ]))
+}
+
+/// @dev Admin function to set (or remove) the guardian, who may pause operations
+/// @param guardian : (Optional) Guardian address
+pub fn try_update_guardian(
+    deps: DepsMut,
+    info: MessageInfo,
+    guardian: Option<String>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let guardian = guardian
+        .map(|guardian| deps.api.addr_validate(&guardian))
+        .transpose()?;
+
+    pause::update_guardian(deps.storage, &info.sender, &config.admin, guardian.clone())?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "update_guardian"),
+        attr(
+            "guardian",
+            guardian
+                .map(|guardian| guardian.to_string())
+                .unwrap_or_default(),
+        ),
+    ]))
+}
+
+/// @dev Pauses operations. Can be called by the guardian or the admin
+/// @param flags : Operations to pause
+pub fn try_pause(
+    deps: DepsMut,
+    info: MessageInfo,
+    flags: Vec<PauseFlag>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    pause::pause(deps.storage, &info.sender, &config.admin, &flags)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "pause"),
+        attr("flags", pause::flags_to_string(&flags)),
+    ]))
+}
+
+/// @dev Admin function to lift the pause of operations
+/// @param flags : Operations to unpause
+pub fn try_unpause(
+    deps: DepsMut,
+    info: MessageInfo,
+    flags: Vec<PauseFlag>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    pause::unpause(deps.storage, &info.sender, &config.admin, &flags)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "unpause"),
+        attr("flags", pause::flags_to_string(&flags)),
+    ]))
+}
+
+/// @dev Stores signature details of the Signee. The Martian date and time are derived from block time. https://manifesto.marsprotocol.io/ : Web app to facilitate signing with Martian Date and Time
+/// @param manifesto_id : Manifesto to sign
+/// @param content_hash : Hash of the manifesto text the signee agrees to, which must match the current version
+/// @param martian_date : (Optional) Martian date as seen by the signee according to the Darian Calender, e.g. "26 Taurus 219"
+/// @param martian_time : (Optional) Coordinated Martian Time as seen by the signee, e.g. "09:05:33 MTC"
+/// @param proof : (Optional) Merkle proof of the signee address, if the signing round has an allowlist
+pub fn try_sign_manifesto(
+    deps: DepsMut,
+    env: Env,
+    signee: Addr,
+    funds: Vec<Coin>,
+    manifesto_id: u64,
+    content_hash: String,
+    martian_date_: Option<String>,
+    martian_time_: Option<String>,
+    proof: Option<Vec<String>>,
+) -> Result<Response, ContractError> {
+    pause::assert_not_paused(deps.storage, PauseFlag::Signing)?;
+    migrations::assert_legacy_signatures_migrated(deps.storage, manifesto_id)?;
+
+    let config = CONFIG.load(deps.storage)?;
+    let manifesto = load_manifesto(deps.as_ref(), manifesto_id)?;
+    let mut state = STATE.load(deps.storage, U64Key::new(manifesto_id))?;
+
+    // Verify if the signee agrees to the current version of the text
+    let version = load_version(deps.as_ref(), manifesto_id, manifesto.version)?;
+    if !content_hash.eq_ignore_ascii_case(&version.content_hash) {
+        return Err(ContractError::ContentHashMismatch {
+            manifesto_id,
+            version: version.version,
+        });
+    }
+
+    // Martian date and time at which the signature is recorded
+    let (martian_date, martian_time) = darian::from_timestamp(env.block.time);
+
+    // Verify if the Martian date and time sent by the signee match block time
+    match (martian_date_, martian_time_) {
+        (None, None) => {}
+        (Some(date), Some(time)) => {
+            let signee_time =
+                darian::to_timestamp(&date.parse::<MartianDate>()?, &time.parse::<MartianTime>()?)?;
+            let drift = if signee_time > env.block.time {
+                signee_time.seconds() - env.block.time.seconds()
+            } else {
+                env.block.time.seconds() - signee_time.seconds()
+            };
+            if drift > config.time_tolerance {
+                return Err(ContractError::MartianDateTimeDrift {
+                    drift,
+                    time_tolerance: config.time_tolerance,
+                });
+            }
+        }
+        _ => return Err(ContractError::IncompleteMartianDateTime {}),
+    }
+
+    // Signees of a previous version re-sign the current one, keeping their signature and MEDAL
+    let signature_key = (U64Key::new(manifesto_id), signee.as_bytes());
+    if let Some(mut signature_) = signatures().may_load(deps.storage, signature_key.clone())? {
+        if signature_.withdrawn_at.is_some() {
+            return Err(ContractError::SignatureWithdrawn {});
+        }
+        if signature_.version == manifesto.version {
+            return Err(ContractError::AlreadySigned {});
+        }
+        if !funds.is_empty() {
+            return Err(ContractError::FundsNotRequired {});
+        }
+        signature_.version = manifesto.version;
+        signatures().save(deps.storage, signature_key, &signature_)?;
+
+        return Ok(Response::new().add_attributes(vec![
+            attr("action", "resign_manifesto"),
+            attr("manifesto_id", manifesto_id.to_string()),
+            attr("signee", signee),
+            attr("version", manifesto.version.to_string()),
+        ]));
+    }
+
+    // Verify if signee limit is not reached yet
+    if state.signees_count >= manifesto.max_signees_allowed {
+        return Err(ContractError::MaxSigneesReached {
+            signees_count: state.signees_count,
+            max_signees_allowed: manifesto.max_signees_allowed,
+        });
+    }
+
+    // Verify if a signing phase is active and its signee limit is not reached yet
+    let phases = load_signing_phases(deps.storage, manifesto_id)?;
+    if !phases.is_empty() {
+        let phase = active_phase(&phases, &env.block).ok_or(ContractError::NoActivePhase {})?;
+
+        let phase_key = (U64Key::new(manifesto_id), U32Key::new(phase.id));
+        let phase_signees = PHASE_SIGNEES
+            .may_load(deps.storage, phase_key.clone())?
+            .unwrap_or_default();
+        if phase_signees >= phase.max_signees {
+            return Err(ContractError::PhaseMaxSigneesReached {
+                phase_id: phase.id,
+                signees_count: phase_signees,
+                max_signees: phase.max_signees,
+            });
+        }
+        PHASE_SIGNEES.save(deps.storage, phase_key, &(phase_signees + 1))?;
+    }
+
+    // Verify if the signee is on the allowlist of the signing round
+    let round = signing_round(&phases, &env.block);
+    let round_key = (U64Key::new(manifesto_id), U32Key::new(round));
+    if let Some(merkle_root) = MERKLE_ROOTS.may_load(deps.storage, round_key)? {
+        let proof = proof.ok_or(ContractError::MerkleProofRequired { round })?;
+        if !verify_merkle_proof(&merkle_root, signee.as_str(), &proof)? {
+            return Err(ContractError::NotEligible { round });
+        }
+    }
+
+    // Verify if the signing fee is paid, and forward it to the treasury
+    let fee_msg = collect_signing_fee(deps.as_ref(), &config, funds)?;
+
+    state.signees_count += 1;
+    let signature_ = Signature {
+        manifesto_id,
+        signee: signee.clone(),
+        martian_date: martian_date.to_string(),
+        martian_time: martian_time.to_string(),
+        ordinal: state.signees_count,
+        medal_token_id: None,
+        version: manifesto.version,
+        block_height: env.block.height,
+        signed_at: env.block.time,
+        withdrawn_at: None,
+    };
+
+    STATE.save(
+        deps.storage,
+        U64Key::new(manifesto_id),
+        &state,
+        env.block.height,
+    )?;
+    SIGNEES.save(
+        deps.storage,
+        signature_key.clone(),
+        &signature_.ordinal,
+        env.block.height,
+    )?;
+    signatures().save(deps.storage, signature_key, &signature_)?;
+    count_signature_date(deps.storage, manifesto_id, &martian_date)?;
+
+    // The signature is kept with a pending MEDAL if the mint fails
+    let medal_mint_msg = mint_medal(deps, &manifesto, &signature_)?;
+
+    let mut response = Response::new()
+        .add_submessage(medal_mint_msg)
+        .add_event(events::manifesto_sign_event(
+            CONTRACT_VERSION,
+            manifesto_id,
+            &signee,
+            signature_.ordinal,
+            &signature_.martian_date,
+        ))
+        .add_attributes(vec![
+            attr("action", "sign_manifesto"),
+            attr("manifesto_id", manifesto_id.to_string()),
+            attr("signee", signee),
+            attr("signee_count", state.signees_count.to_string()),
+            attr("version", manifesto.version.to_string()),
+        ])
+        .set_data(to_binary(&ManifestoSignData {
+            manifesto_id,
+            ordinal: signature_.ordinal,
+        })?);
+    if let Some((fee_msg, fee)) = fee_msg {
+        response = response
+            .add_message(fee_msg)
+            .add_attribute("signing_fee", fee.to_string());
+    }
+
+    Ok(response)
+}
+
+/// @dev Relays a signature signed off-chain by the Signee, so that they don't need to pay gas. The
+/// relayer pays the signing fee, if any
+/// @param signee_pubkey : Compressed secp256k1 public key of the signee
+/// @param signature : Signature of the SHA-256 hash of the JSON encoded SignManifestoPayload
+/// @param nonce : Next nonce of the signee, incremented by every relayed signature
+pub fn try_sign_manifesto_for(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    manifesto_id: u64,
+    content_hash: String,
+    signee_pubkey: Binary,
+    signature: Binary,
+    martian_date: Option<String>,
+    martian_time: Option<String>,
+    nonce: u64,
+    proof: Option<Vec<String>>,
+) -> Result<Response, ContractError> {
+    let signee = pubkey_to_address(deps.as_ref(), &env, &signee_pubkey)?;
+
+    // Verify if the nonce has not been used yet
+    let expected_nonce = NONCES
+        .may_load(deps.storage, signee.as_bytes())?
+        .unwrap_or_default();
+    if nonce != expected_nonce {
+        return Err(ContractError::InvalidNonce {
+            nonce,
+            expected: expected_nonce,
+        });
+    }
+
+    // Verify if the payload was signed by the signee
+    let payload = SignManifestoPayload {
+        chain_id: env.block.chain_id.clone(),
+        contract_addr: env.contract.address.to_string(),
+        manifesto_id,
+        content_hash: content_hash.clone(),
+        martian_date: martian_date.clone(),
+        martian_time: martian_time.clone(),
+        nonce,
+    };
+    let payload_hash = sha2::Sha256::digest(&to_vec(&payload)?);
+    let is_valid = deps
+        .api
+        .secp256k1_verify(&payload_hash, &signature, &signee_pubkey)
+        .map_err(|err| match err {
+            VerificationError::InvalidPubkeyFormat => ContractError::InvalidPubkey {},
+            _ => ContractError::InvalidSignature {},
+        })?;
+    if !is_valid {
+        return Err(ContractError::InvalidSignature {});
+    }
+
+    NONCES.save(deps.storage, signee.as_bytes(), &(nonce + 1))?;
+
+    let response = try_sign_manifesto(
+        deps,
+        env,
+        signee,
+        info.funds,
+        manifesto_id,
+        content_hash,
+        martian_date,
+        martian_time,
+        proof,
+    )?;
+
+    Ok(response.add_attribute("relayer", info.sender))
+}
+
+/// @dev Withdraws the signature of the Signee and burns their MEDAL. The MEDAL contract leaves it
+/// untouched if it was redeemed or transferred in the meantime
+/// @param manifesto_id : Manifesto to withdraw the signature from
+pub fn try_withdraw_signature(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    manifesto_id: u64,
+) -> Result<Response, ContractError> {
+    let manifesto = load_manifesto(deps.as_ref(), manifesto_id)?;
+    let mut state = STATE.load(deps.storage, U64Key::new(manifesto_id))?;
+    let signee = info.sender;
+
+    let signature_key = (U64Key::new(manifesto_id), signee.as_bytes());
+    let mut signature_ = signatures()
+        .may_load(deps.storage, signature_key.clone())?
+        .ok_or(ContractError::NotSigned {})?;
+    if signature_.withdrawn_at.is_some() {
+        return Err(ContractError::AlreadyWithdrawn {});
+    }
+
+    signature_.withdrawn_at = Some(env.block.time);
+    state.withdrawn_count += 1;
+
+    STATE.save(
+        deps.storage,
+        U64Key::new(manifesto_id),
+        &state,
+        env.block.height,
+    )?;
+    SIGNEES.remove(deps.storage, signature_key.clone(), env.block.height)?;
+    signatures().save(deps.storage, signature_key, &signature_)?;
+    // Signatures migrated from v1.0.0 may have Martian dates which were never counted
+    if let Ok(martian_date) = signature_.martian_date.parse::<MartianDate>() {
+        uncount_signature_date(deps.storage, manifesto_id, &martian_date)?;
+    }
+
+    // Nothing to burn if the MEDAL mint is pending
+    let mut response = Response::new();
+    if let Some(medal_token_id) = &signature_.medal_token_id {
+        if manifesto.medal_addr != zero_address() {
+            response = response.add_message(build_medal_burn_msg(
+                manifesto.medal_addr.to_string(),
+                medal_token_id.clone(),
+                signee.to_string(),
+            )?);
+        }
+    }
+
+    Ok(response.add_attributes(vec![
+        attr("action", "withdraw_signature"),
+        attr("manifesto_id", manifesto_id.to_string()),
+        attr("signee", signee),
+        attr(
+            "medal_token_id",
+            signature_.medal_token_id.unwrap_or_default(),
+        ),
+    ]))
+}
+
+/// @dev Mints again the MEDAL of the Signee, if minting it failed when they signed
+/// @param manifesto_id : Manifesto the signee signed
+pub fn try_retry_medal_mint(
+    deps: DepsMut,
+    info: MessageInfo,
+    manifesto_id: u64,
+) -> Result<Response, ContractError> {
+    let manifesto = load_manifesto(deps.as_ref(), manifesto_id)?;
+    let signee = info.sender;
+
+    let signature_ = signatures()
+        .may_load(deps.storage, (U64Key::new(manifesto_id), signee.as_bytes()))?
+        .ok_or(ContractError::NotSigned {})?;
+    if signature_.withdrawn_at.is_some() {
+        return Err(ContractError::SignatureWithdrawn {});
+    }
+    if let Some(token_id) = signature_.medal_token_id {
+        return Err(ContractError::MedalAlreadyMinted { token_id });
+    }
+
+    let medal_mint_msg = mint_medal(deps, &manifesto, &signature_)?;
+
+    Ok(Response::new()
+        .add_submessage(medal_mint_msg)
+        .add_attributes(vec![
+            attr("action", "retry_medal_mint"),
+            attr("manifesto_id", manifesto_id.to_string()),
+            attr("signee", signee),
+        ]))
+}
+
+/// @dev Admin function to import signatures gathered off-chain or on another deployment
+/// @param manifesto_id : Manifesto the signatures are imported to
+/// @param entries : Signatures, whose ordinals must follow the last ordinal of the manifesto
+/// @param mint_medals : Mints the MEDALs of the signees in the same transaction if true, confirmed one by one by the replies of the mints. MEDALs are otherwise pending, as are the ones whose mint fails, and can be minted with retry_medal_mint
+pub fn try_import_signatures(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    manifesto_id: u64,
+    entries: Vec<ImportedSignature>,
+    mint_medals: bool,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // Verify if called by Admin
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    pause::assert_not_paused(deps.storage, PauseFlag::Signing)?;
+    if IMPORT_FINALIZED.may_load(deps.storage)?.unwrap_or_default() {
+        return Err(ContractError::ImportFinalized {});
+    }
+    migrations::assert_legacy_signatures_migrated(deps.storage, manifesto_id)?;
+
+    let manifesto = load_manifesto(deps.as_ref(), manifesto_id)?;
+    let mut state = STATE.load(deps.storage, U64Key::new(manifesto_id))?;
+
+    let mut medal_mint_msgs = vec![];
+    let mut sign_events = vec![];
+    for entry in entries {
+        let signee = deps.api.addr_validate(&entry.signee)?;
+        let signature_key = (U64Key::new(manifesto_id), signee.as_bytes());
+        if signatures()
+            .may_load(deps.storage, signature_key.clone())?
+            .is_some()
+        {
+            return Err(ContractError::DuplicateSignature {
+                signee: entry.signee,
+            });
+        }
+
+        // Ordinals of imported signatures continue the ones of the manifesto
+        if entry.ordinal != state.signees_count + 1 {
+            return Err(ContractError::UnexpectedOrdinal {
+                ordinal: entry.ordinal,
+                expected: state.signees_count + 1,
+            });
+        }
+        if state.signees_count >= manifesto.max_signees_allowed {
+            return Err(ContractError::MaxSigneesReached {
+                signees_count: state.signees_count,
+                max_signees_allowed: manifesto.max_signees_allowed,
+            });
+        }
+
+        let martian_date = entry.martian_date.parse::<MartianDate>()?;
+        let martian_time = entry.martian_time.parse::<MartianTime>()?;
+
+        state.signees_count += 1;
+        let signature_ = Signature {
+            manifesto_id,
+            signee: signee.clone(),
+            martian_date: martian_date.to_string(),
+            martian_time: martian_time.to_string(),
+            ordinal: entry.ordinal,
+            medal_token_id: None,
+            version: manifesto.version,
+            block_height: env.block.height,
+            signed_at: darian::to_timestamp(&martian_date, &martian_time)?,
+            withdrawn_at: None,
+        };
+
+        // MEDALs are pending until the replies of their mints confirm them
+        if mint_medals {
+            medal_mint_msgs.push(mint_medal(deps.branch(), &manifesto, &signature_)?);
+        }
+
+        SIGNEES.save(
+            deps.storage,
+            signature_key.clone(),
+            &signature_.ordinal,
+            env.block.height,
+        )?;
+        signatures().save(deps.storage, signature_key, &signature_)?;
+        count_signature_date(deps.storage, manifesto_id, &martian_date)?;
+        sign_events.push(events::manifesto_sign_event(
+            CONTRACT_VERSION,
+            manifesto_id,
+            &signee,
+            signature_.ordinal,
+            &signature_.martian_date,
+        ));
+    }
+
+    STATE.save(
+        deps.storage,
+        U64Key::new(manifesto_id),
+        &state,
+        env.block.height,
+    )?;
+
+    Ok(Response::new()
+        .add_submessages(medal_mint_msgs)
+        .add_events(sign_events)
+        .add_attributes(vec![
+            attr("action", "import_signatures"),
+            attr("manifesto_id", manifesto_id.to_string()),
+            attr("signee_count", state.signees_count.to_string()),
+        ]))
+}
+
+/// @dev Admin function to disable importing signatures for good
+pub fn try_finalize_import(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // Verify if called by Admin
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    IMPORT_FINALIZED.save(deps.storage, &true)?;
+
+    Ok(Response::new().add_attribute("action", "finalize_import"))
+}
+
+/// @dev Admin function to move the signatures of a migrated v1.0.0 contract to manifesto 1, in batches
+/// @param entries : Signees with the token id of the MEDAL v1.0.0 minted to them, which becomes their ordinal
+pub fn try_migrate_legacy_signatures(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    entries: Vec<LegacyMedal>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // Verify if called by Admin
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let migrated = entries.len();
+    migrations::migrate_legacy_signatures(deps.branch(), env.block.height, entries)?;
+    let done = LEGACY_SIGNATURES_MIGRATED.may_load(deps.storage)?.is_none();
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "migrate_legacy_signatures"),
+        attr("migrated", migrated.to_string()),
+        attr("done", done.to_string()),
+    ]))
+}
+
+//----------------------------------------------------------------------------------------
+// Reply functions
+//----------------------------------------------------------------------------------------
+
+/// @dev Stores the token id of a minted MEDAL, reported by the `MedalMintData` the MEDAL contract
+/// sets as response data, or else by the `token_id` attribute of its `wasm` event. If the mint
+/// failed or its token id is not reported, the MEDAL stays pending so that the signee can retry it
+fn handle_medal_mint_reply(
+    deps: DepsMut,
+    result: ContractResult<SubMsgExecutionResponse>,
+) -> Result<Response, ContractError> {
+    // Replies are handled in the order the mints were sent
+    let mut pending_mints = PENDING_MINTS.load(deps.storage)?;
+    let pending = pending_mints.remove(0);
+    if pending_mints.is_empty() {
+        PENDING_MINTS.remove(deps.storage);
+    } else {
+        PENDING_MINTS.save(deps.storage, &pending_mints)?;
+    }
+
+    let response = Response::new().add_attributes(vec![
+        attr("manifesto_id", pending.manifesto_id.to_string()),
+        attr("signee", pending.signee.clone()),
+    ]);
+
+    let execution_response = match result {
+        ContractResult::Ok(execution_response) => execution_response,
+        ContractResult::Err(err) => {
+            return Ok(response.add_attributes(vec![
+                attr("action", "medal_mint_failed"),
+                attr("error", err),
+            ]))
+        }
+    };
+    // MEDAL contracts deployed before `MedalMintData` only report the token id in their events
+    let token_id = execution_response
+        .data
+        .as_ref()
+        .and_then(|data| parse_medal_mint_data(data))
+        .map(|data| data.token_id)
+        .or_else(|| {
+            execution_response
+                .events
+                .iter()
+                .filter(|event| event.ty == "wasm")
+                .flat_map(|event| event.attributes.iter())
+                .find(|attribute| attribute.key == "token_id")
+                .map(|attribute| attribute.value.clone())
+        });
+    let token_id = match token_id {
+        Some(token_id) => token_id,
+        None => return Ok(response.add_attribute("action", "medal_mint_unconfirmed")),
+    };
+
+    let signature_key = (U64Key::new(pending.manifesto_id), pending.signee.as_bytes());
+    let mut signature_ = signatures().load(deps.storage, signature_key.clone())?;
+    signature_.medal_token_id = Some(token_id.clone());
+    signatures().save(deps.storage, signature_key, &signature_)?;
+
+    count_tier_mint(deps, pending.manifesto_id, signature_.ordinal)?;
+
+    Ok(response.add_attributes(vec![
+        attr("action", "confirm_medal_mint"),
+        attr("medal_token_id", token_id),
+    ]))
+}
+
+//----------------------------------------------------------------------------------------
+// Query functions
+//----------------------------------------------------------------------------------------
+
+/// @dev Returns the admin and the number of manifestos registered
+fn query_config(deps: Deps) -> Result<ConfigResponse, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    Ok(ConfigResponse {
+        admin: config.admin.to_string(),
+        time_tolerance: config.time_tolerance,
+        manifestos_count: config.manifestos_count,
+        signing_fee: config.signing_fee,
+        import_finalized: IMPORT_FINALIZED.may_load(deps.storage)?.unwrap_or_default(),
+    })
+}
+
+/// @dev Returns the admin proposed by the current one, if any
+fn query_admin_proposal(deps: Deps) -> Result<AdminProposalResponse, ContractError> {
+    let proposal = ADMIN_PROPOSAL.may_load(deps.storage)?;
+
+    Ok(AdminProposalResponse {
+        new_admin: proposal.as_ref().map(|p| p.new_admin.to_string()),
+        expires: proposal.map(|p| p.expires),
+    })
+}
+
+/// @dev Returns the Medal token address and max signee's allowed to sign a Manifesto
+fn query_manifesto(deps: Deps, manifesto_id: u64) -> Result<ManifestoResponse, ContractError> {
+    let manifesto = load_manifesto(deps, manifesto_id)?;
+    manifesto_response(deps, manifesto)
+}
+
+/// @dev Returns all manifestos ordered by id
+fn query_manifestos(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> Result<ManifestosResponse, ContractError> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive_int);
+
+    let manifestos = MANIFESTOS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (_, manifesto) = item?;
+            manifesto_response(deps, manifesto)
+        })
+        .collect::<Result<Vec<_>, ContractError>>()?;
+
+    Ok(ManifestosResponse { manifestos })
+}
+
+/// @dev Returns a version of the manifesto text, defaulting to the current one
+fn query_manifesto_version(
+    deps: Deps,
+    manifesto_id: u64,
+    version: Option<u32>,
+) -> Result<ManifestoVersionResponse, ContractError> {
+    let version = match version {
+        Some(version) => version,
+        None => load_manifesto(deps, manifesto_id)?.version,
+    };
+    let version = load_version(deps, manifesto_id, version)?;
+    Ok(version_response(manifesto_id, version))
+}
+
+/// @dev Returns all versions of the manifesto text, oldest first
+fn query_manifesto_versions(
+    deps: Deps,
+    manifesto_id: u64,
+    start_after: Option<u32>,
+    limit: Option<u32>,
+) -> Result<ManifestoVersionsResponse, ContractError> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive_int);
+
+    let versions: StdResult<Vec<ManifestoVersionResponse>> = VERSIONS
+        .prefix(U64Key::new(manifesto_id))
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, version)| version_response(manifesto_id, version)))
+        .collect();
+
+    Ok(ManifestoVersionsResponse {
+        versions: versions?,
+    })
+}
+
+/// @dev Returns the template of the traits of the MEDALs of a manifesto
+fn query_medal_template(deps: Deps, manifesto_id: u64) -> Result<MedalTemplate, ContractError> {
+    load_manifesto(deps, manifesto_id)?;
+    Ok(load_medal_template(deps, manifesto_id)?)
+}
+
+/// @dev Returns the MEDAL tiers of a manifesto and the number of MEDALs minted in each of them
+fn query_medal_tiers(deps: Deps, manifesto_id: u64) -> Result<MedalTiersResponse, ContractError> {
+    load_manifesto(deps, manifesto_id)?;
+
+    let tiers = MEDAL_TIERS
+        .may_load(deps.storage, U64Key::new(manifesto_id))?
+        .unwrap_or_default()
+        .into_iter()
+        .map(|tier| {
+            let minted_count = TIER_MINTS
+                .may_load(deps.storage, (U64Key::new(manifesto_id), &tier.name))?
+                .unwrap_or_default();
+            Ok(MedalTierResponse { tier, minted_count })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(MedalTiersResponse { tiers })
+}
+
+/// @dev Returns the total number of Signee's that have signed a Manifesto
+fn query_state(deps: Deps, manifesto_id: u64) -> Result<StateResponse, ContractError> {
+    let state = STATE.load(deps.storage, U64Key::new(manifesto_id))?;
+
+    Ok(StateResponse {
+        signee_count: state.signees_count,
+        withdrawn_count: state.withdrawn_count,
+        active_signee_count: state.signees_count - state.withdrawn_count,
+    })
+}
+
+/// @dev Returns the signing state of a manifesto as of the end of block `height`. Counts are zero
+/// if the manifesto was not registered yet
+fn query_state_at_height(
+    deps: Deps,
+    manifesto_id: u64,
+    height: u64,
+) -> Result<StateResponse, ContractError> {
+    load_manifesto(deps, manifesto_id)?;
+    // Snapshots are taken at the start of a block, so the end of `height` is the start of the next
+    let state = STATE
+        .may_load_at_height(
+            deps.storage,
+            U64Key::new(manifesto_id),
+            height.saturating_add(1),
+        )?
+        .unwrap_or(State {
+            signees_count: 0,
+            withdrawn_count: 0,
+        });
+
+    Ok(StateResponse {
+        signee_count: state.signees_count,
+        withdrawn_count: state.withdrawn_count,
+        active_signee_count: state.signees_count - state.withdrawn_count,
+    })
+}
+
+/// @dev Returns Signauture details of the signee
+fn get_signature(
+    deps: Deps,
+    manifesto_id: u64,
+    signee: String,
+) -> Result<SignatureResponse, ContractError> {
+    let signature_ =
+        signatures().load(deps.storage, (U64Key::new(manifesto_id), signee.as_bytes()))?;
+    Ok(signature_response(signature_))
+}
+
+/// @dev Returns Signature details of the n-th signee of a manifesto
+fn query_signature_by_ordinal(
+    deps: Deps,
+    manifesto_id: u64,
+    ordinal: u64,
+) -> Result<SignatureResponse, ContractError> {
+    let (_, signature_) = signatures()
+        .idx
+        .ordinal
+        .item(
+            deps.storage,
+            (U64Key::new(manifesto_id), U64Key::new(ordinal)),
+        )?
+        .ok_or_else(|| StdError::not_found("Signature"))?;
+    Ok(signature_response(signature_))
+}
+
+/// @dev Returns Signature details of the signees of a manifesto who signed on a Martian date, in
+/// signing order
+fn query_signatures_by_date(
+    deps: Deps,
+    manifesto_id: u64,
+    date: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> Result<SignaturesResponse, ContractError> {
+    let date = date.parse::<MartianDate>()?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive_int);
+
+    let signatures_: StdResult<Vec<SignatureResponse>> = signatures()
+        .idx
+        .date
+        .prefix((
+            U64Key::new(manifesto_id),
+            U64Key::new(date.sols_since_epoch()),
+        ))
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, signature_)| signature_response(signature_)))
+        .collect();
+
+    Ok(SignaturesResponse {
+        signatures: signatures_?,
+    })
+}
+
+/// @dev Returns the number of signatures per sol between two Martian dates (inclusive), and per
+/// Darian month overlapping them. Sols and months without signatures are left out
+fn query_signature_histogram(
+    deps: Deps,
+    manifesto_id: u64,
+    from: String,
+    to: String,
+) -> Result<SignatureHistogramResponse, ContractError> {
+    let from_date = from.parse::<MartianDate>()?;
+    let to_date = to.parse::<MartianDate>()?;
+    let (first_sol, last_sol) = (from_date.sols_since_epoch(), to_date.sols_since_epoch());
+    if first_sol > last_sol {
+        return Err(ContractError::InvalidDateRange { from, to });
+    }
+    if last_sol - first_sol >= MAX_HISTOGRAM_SOLS {
+        return Err(ContractError::DateRangeTooLong {
+            sols: last_sol - first_sol + 1,
+            max_sols: MAX_HISTOGRAM_SOLS,
+        });
+    }
+
+    let sols = SOL_SIGNATURES
+        .prefix(U64Key::new(manifesto_id))
+        .range(
+            deps.storage,
+            Some(Bound::inclusive_int(first_sol)),
+            Some(Bound::inclusive_int(last_sol)),
+            Order::Ascending,
+        )
+        .map(|item| {
+            let (key, count) = item?;
+            let msd = u64::from_be_bytes(int_key(&key)?) as i64 + darian::DARIAN_EPOCH_MSD;
+            Ok(SolCount {
+                martian_date: MartianDate::from_mars_sol_date(msd)?.to_string(),
+                count,
+            })
+        })
+        .collect::<Result<Vec<_>, ContractError>>()?;
+
+    let months = MONTH_SIGNATURES
+        .prefix(U64Key::new(manifesto_id))
+        .range(
+            deps.storage,
+            Some(Bound::inclusive_int(month_key(&from_date))),
+            Some(Bound::inclusive_int(month_key(&to_date))),
+            Order::Ascending,
+        )
+        .map(|item| {
+            let (key, count) = item?;
+            let month_key = u64::from_be_bytes(int_key(&key)?);
+            Ok(MonthCount {
+                year: (month_key / 24) as u32,
+                month: darian::MONTH_NAMES[(month_key % 24) as usize].to_string(),
+                count,
+            })
+        })
+        .collect::<Result<Vec<_>, ContractError>>()?;
+
+    Ok(SignatureHistogramResponse { sols, months })
+}
+
+/// @dev Checks if the address has an active signature
+fn query_is_signee(
+    deps: Deps,
+    manifesto_id: u64,
+    address: String,
+) -> Result<SigneeResponse, ContractError> {
+    load_manifesto(deps, manifesto_id)?;
+
+    let address = deps.api.addr_validate(&address)?;
+    let is_signee = has_signed(deps, manifesto_id, &address)?;
+    Ok(SigneeResponse { address, is_signee })
+}
+
+/// @dev Checks if each of the addresses has an active signature. Invalid addresses are reported as not signed
+fn query_are_signees(
+    deps: Deps,
+    manifesto_id: u64,
+    addresses: Vec<String>,
+) -> Result<SigneesResponse, ContractError> {
+    if addresses.len() > MAX_ADDRESSES {
+        return Err(ContractError::TooManyAddresses {
+            count: addresses.len(),
+            max_addresses: MAX_ADDRESSES,
+        });
+    }
+    load_manifesto(deps, manifesto_id)?;
+
+    let signees = addresses
+        .into_iter()
+        .map(|address| match deps.api.addr_validate(&address) {
+            Ok(address) => Ok(SigneeResponse {
+                is_signee: has_signed(deps, manifesto_id, &address)?,
+                address,
+            }),
+            Err(_) => Ok(SigneeResponse {
+                address: Addr::unchecked(address),
+                is_signee: false,
+            }),
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(SigneesResponse { signees })
+}
+
+/// Returns true if `address` has an active signature
+fn has_signed(deps: Deps, manifesto_id: u64, address: &Addr) -> StdResult<bool> {
+    Ok(SIGNEES
+        .may_load(
+            deps.storage,
+            (U64Key::new(manifesto_id), address.as_bytes()),
+        )?
+        .is_some())
+}
+
+/// @dev Checks if the signee had an active signature as of the end of block `height`
+fn query_signature_at_height(
+    deps: Deps,
+    manifesto_id: u64,
+    signee: String,
+    height: u64,
+) -> Result<SignatureAtHeightResponse, ContractError> {
+    load_manifesto(deps, manifesto_id)?;
+
+    let signee = deps.api.addr_validate(&signee)?;
+    let ordinal = SIGNEES.may_load_at_height(
+        deps.storage,
+        (U64Key::new(manifesto_id), signee.as_bytes()),
+        height.saturating_add(1),
+    )?;
+
+    Ok(SignatureAtHeightResponse {
+        signee,
+        signed: ordinal.is_some(),
+        ordinal,
+    })
+}
+
+/// @dev Returns Signature details of all signees of a manifesto in signing order
+fn query_all_signatures(
+    deps: Deps,
+    manifesto_id: u64,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+    order: Option<OrderBy>,
+) -> Result<SignaturesResponse, ContractError> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive_int);
+    let (min, max, order) = match order {
+        Some(OrderBy::Desc) => (None, start, Order::Descending),
+        _ => (start, None, Order::Ascending),
+    };
+
+    let signatures_: StdResult<Vec<SignatureResponse>> = signatures()
+        .idx
+        .ordinal
+        .prefix(U64Key::new(manifesto_id))
+        .range(deps.storage, min, max, order)
+        .take(limit)
+        .map(|item| item.map(|(_, signature_)| signature_response(signature_)))
+        .collect();
+
+    Ok(SignaturesResponse {
+        signatures: signatures_?,
+    })
+}
+
+/// @dev Returns the next nonce of relayed signatures of an address
+fn query_nonce(deps: Deps, address: String) -> Result<NonceResponse, ContractError> {
+    let nonce = NONCES
+        .may_load(deps.storage, address.as_bytes())?
+        .unwrap_or_default();
+    Ok(NonceResponse { nonce })
+}
+
+/// @dev Returns the active signing phase of a manifesto and the ones that have not started yet
+fn query_phases(deps: Deps, env: Env, manifesto_id: u64) -> Result<PhasesResponse, ContractError> {
+    load_manifesto(deps, manifesto_id)?;
+    let phases = load_signing_phases(deps.storage, manifesto_id)?;
+
+    let phase_response = |phase: &SigningPhase| -> Result<PhaseResponse, ContractError> {
+        Ok(PhaseResponse {
+            phase: phase.clone(),
+            signees_count: PHASE_SIGNEES
+                .may_load(
+                    deps.storage,
+                    (U64Key::new(manifesto_id), U32Key::new(phase.id)),
+                )?
+                .unwrap_or_default(),
+        })
+    };
+
+    let current = active_phase(&phases, &env.block)
+        .map(phase_response)
+        .transpose()?;
+    let upcoming = phases
+        .iter()
+        .filter(|phase| !phase.start.is_reached(&env.block))
+        .map(phase_response)
+        .collect::<Result<Vec<_>, ContractError>>()?;
+
+    Ok(PhasesResponse { current, upcoming })
+}
+
+/// @dev Returns whether an address can sign a manifesto during a signing round (defaults to the current one)
+fn query_is_eligible(
+    deps: Deps,
+    env: Env,
+    manifesto_id: u64,
+    address: String,
+    proof: Option<Vec<String>>,
+    round: Option<u32>,
+) -> Result<EligibilityResponse, ContractError> {
+    load_manifesto(deps, manifesto_id)?;
+    let round = match round {
+        Some(round) => round,
+        None => signing_round(
+            &load_signing_phases(deps.storage, manifesto_id)?,
+            &env.block,
+        ),
+    };
+    let merkle_root = MERKLE_ROOTS.may_load(
+        deps.storage,
+        (U64Key::new(manifesto_id), U32Key::new(round)),
+    )?;
+
+    let is_eligible = match (&merkle_root, proof) {
+        (None, _) => true,
+        (Some(_), None) => false,
+        (Some(root), Some(proof)) => verify_merkle_proof(root, &address, &proof)?,
+    };
+
+    Ok(EligibilityResponse {
+        round,
+        merkle_root,
+        is_eligible,
+    })
+}
+
+//----------------------------------------------------------------------------------------
+// Helper functions
+//----------------------------------------------------------------------------------------
+
+/// Loads a manifesto, failing with a readable error if it is not registered
+fn load_manifesto(deps: Deps, manifesto_id: u64) -> Result<Manifesto, ContractError> {
+    MANIFESTOS
+        .may_load(deps.storage, U64Key::new(manifesto_id))?
+        .ok_or(ContractError::ManifestoNotFound { manifesto_id })
+}
+
+/// Loads a version of the manifesto text
+fn load_version(
+    deps: Deps,
+    manifesto_id: u64,
+    version: u32,
+) -> Result<ManifestoVersion, ContractError> {
+    VERSIONS
+        .may_load(
+            deps.storage,
+            (U64Key::new(manifesto_id), U32Key::new(version)),
+        )?
+        .ok_or(ContractError::VersionNotFound {
+            manifesto_id,
+            version,
+        })
+}
+
+/// Loads the template of the MEDAL traits of a manifesto, the default one if it has none
+fn load_medal_template(deps: Deps, manifesto_id: u64) -> StdResult<MedalTemplate> {
+    Ok(MEDAL_TEMPLATES
+        .may_load(deps.storage, U64Key::new(manifesto_id))?
+        .unwrap_or_default())
+}
+
+/// Loads the MEDAL tier of an ordinal, if any
+fn load_medal_tier(deps: Deps, manifesto_id: u64, ordinal: u64) -> StdResult<Option<MedalTier>> {
+    Ok(MEDAL_TIERS
+        .may_load(deps.storage, U64Key::new(manifesto_id))?
+        .unwrap_or_default()
+        .into_iter()
+        .find(|tier| tier.contains(ordinal)))
+}
+
+/// Increments the number of MEDALs minted in the tier of an ordinal, if any
+fn count_tier_mint(deps: DepsMut, manifesto_id: u64, ordinal: u64) -> StdResult<()> {
+    if let Some(tier) = load_medal_tier(deps.as_ref(), manifesto_id, ordinal)? {
+        let tier_key = (U64Key::new(manifesto_id), tier.name.as_str());
+        let minted_count = TIER_MINTS
+            .may_load(deps.storage, tier_key.clone())?
+            .unwrap_or_default();
+        TIER_MINTS.save(deps.storage, tier_key, &(minted_count + 1))?;
+    }
+    Ok(())
+}
+
+/// Increments the number of signatures of the sol and Darian month a signature was signed on
+pub fn count_signature_date(
+    storage: &mut dyn Storage,
+    manifesto_id: u64,
+    martian_date: &MartianDate,
+) -> StdResult<()> {
+    update_signature_date_counts(storage, manifesto_id, martian_date, |count| count + 1)
+}
+
+/// Decrements the number of signatures of the sol and Darian month a withdrawn signature was
+/// signed on, dropping counts which reach zero
+fn uncount_signature_date(
+    storage: &mut dyn Storage,
+    manifesto_id: u64,
+    martian_date: &MartianDate,
+) -> StdResult<()> {
+    update_signature_date_counts(storage, manifesto_id, martian_date, |count| {
+        count.saturating_sub(1)
+    })
+}
+
+fn update_signature_date_counts(
+    storage: &mut dyn Storage,
+    manifesto_id: u64,
+    martian_date: &MartianDate,
+    update: impl Fn(u64) -> u64,
+) -> StdResult<()> {
+    let keys = [
+        (SOL_SIGNATURES, U64Key::new(martian_date.sols_since_epoch())),
+        (MONTH_SIGNATURES, U64Key::new(month_key(martian_date))),
+    ];
+    for (counts, date_key) in keys.iter() {
+        let key = (U64Key::new(manifesto_id), date_key.clone());
+        let count = update(counts.may_load(storage, key.clone())?.unwrap_or_default());
+        if count == 0 {
+            counts.remove(storage, key);
+        } else {
+            counts.save(storage, key, &count)?;
+        }
+    }
+    Ok(())
+}
+
+/// Loads the signing phases of a manifesto, empty if none were set
+fn load_signing_phases(storage: &dyn Storage, manifesto_id: u64) -> StdResult<Vec<SigningPhase>> {
+    Ok(SIGNING_PHASES
+        .may_load(storage, U64Key::new(manifesto_id))?
+        .unwrap_or_default())
+}
+
+/// Returns the first signing phase open at the given block
+fn active_phase<'a>(phases: &'a [SigningPhase], block: &BlockInfo) -> Option<&'a SigningPhase> {
+    phases.iter().find(|phase| phase.is_active(block))
+}
+
+/// Returns the current signing round: the id of the active signing phase, or 0
+fn signing_round(phases: &[SigningPhase], block: &BlockInfo) -> u32 {
+    active_phase(phases, block)
+        .map(|phase| phase.id)
+        .unwrap_or(0)
+}
+
+/// Verifies a Merkle proof of an address. Leaves are the SHA-256 hash of the address, and each
+/// pair of nodes is sorted before being hashed together
+fn verify_merkle_proof(
+    merkle_root: &str,
+    address: &str,
+    proof: &[String],
+) -> Result<bool, ContractError> {
+    let root = decode_hash(merkle_root)?;
+    let leaf: [u8; 32] = sha2::Sha256::digest(address.as_bytes()).into();
+
+    let hash = proof.iter().try_fold(leaf, |hash, node| {
+        let node = decode_hash(node)?;
+        let (first, second) = if hash <= node {
+            (hash, node)
+        } else {
+            (node, hash)
+        };
+        let mut hasher = sha2::Sha256::new();
+        hasher.update(first);
+        hasher.update(second);
+        Ok::<[u8; 32], ContractError>(hasher.finalize().into())
+    })?;
+
+    Ok(hash == root)
+}
+
+/// Derives the address of a compressed secp256k1 public key, the same way Cosmos SDK accounts do.
+/// Uses the bech32 prefix of the contract address
+fn pubkey_to_address(deps: Deps, env: &Env, pubkey: &[u8]) -> Result<Addr, ContractError> {
+    if pubkey.len() != 33 {
+        return Err(ContractError::InvalidPubkey {});
+    }
+    let (prefix, _, _) = bech32::decode(env.contract.address.as_str()).map_err(|_| {
+        ContractError::InvalidContractAddress {
+            address: env.contract.address.to_string(),
+        }
+    })?;
+    let hash = ripemd160::Ripemd160::digest(&sha2::Sha256::digest(pubkey));
+    let address = bech32::encode(&prefix, hash.to_base32(), Variant::Bech32).map_err(|_| {
+        ContractError::InvalidAddressPrefix {
+            prefix: prefix.clone(),
+        }
+    })?;
+    Ok(deps.api.addr_validate(&address)?)
+}
+
+/// Decodes the `MedalMintData` of a MEDAL mint reply. The chain may wrap it in a protobuf
+/// `MsgExecuteContractResponse`, whose only field (1) holds the data set by the contract
+fn parse_medal_mint_data(data: &[u8]) -> Option<MedalMintData> {
+    if let Ok(mint_data) = from_slice(data) {
+        return Some(mint_data);
+    }
+    let (tag, mut rest) = data.split_first()?;
+    if *tag != 0x0a {
+        return None;
+    }
+    let mut length = 0usize;
+    for shift in (0..35).step_by(7) {
+        let (byte, tail) = rest.split_first()?;
+        rest = tail;
+        length |= ((byte & 0x7f) as usize) << shift;
+        if byte & 0x80 == 0 {
+            return if rest.len() == length {
+                from_slice(rest).ok()
+            } else {
+                None
+            };
+        }
+    }
+    None
+}
+
+/// Decodes the big-endian integer key of a range item
+fn int_key<const N: usize>(key: &[u8]) -> Result<[u8; N], ContractError> {
+    key.try_into()
+        .map_err(|_| ContractError::InvalidIntegerKey {
+            length: key.len(),
+            expected: N,
+        })
+}
+
+/// Parses a contract version stored with cw2
+fn parse_version(version: &str) -> Result<Version, ContractError> {
+    version
+        .parse()
+        .map_err(|_| ContractError::InvalidContractVersion {
+            version: version.to_string(),
+        })
+}
+
+/// Decodes a hex encoded SHA-256 hash
+fn decode_hash(hash: &str) -> Result<[u8; 32], ContractError> {
+    let mut bytes = [0u8; 32];
+    hex::decode_to_slice(hash, &mut bytes).map_err(|_| ContractError::InvalidHash {
+        hash: hash.to_string(),
+    })?;
+    Ok(bytes)
+}
+
+fn manifesto_response(
+    deps: Deps,
+    manifesto: Manifesto,
+) -> Result<ManifestoResponse, ContractError> {
+    let state = STATE.load(deps.storage, U64Key::new(manifesto.id))?;
+    let version = load_version(deps, manifesto.id, manifesto.version)?;
+
+    Ok(ManifestoResponse {
+        id: manifesto.id,
+        title: manifesto.title,
+        version: version.version,
+        content_hash: version.content_hash,
+        uri: version.uri,
+        max_signees_allowed: manifesto.max_signees_allowed,
+        medal_addr: manifesto.medal_addr,
+        medal_redeem_addr: manifesto.medal_redeem_addr,
+        signee_count: state.signees_count,
+    })
+}
+
+fn version_response(manifesto_id: u64, version: ManifestoVersion) -> ManifestoVersionResponse {
+    ManifestoVersionResponse {
+        manifesto_id,
+        version: version.version,
+        content_hash: version.content_hash,
+        uri: version.uri,
+        published_at: version.published_at,
+    }
+}
+
+fn signature_response(signature_: Signature) -> SignatureResponse {
+    SignatureResponse {
+        manifesto_id: signature_.manifesto_id,
+        signee: signature_.signee.to_string(),
+        martian_date: signature_.martian_date,
+        martian_time: signature_.martian_time,
+        ordinal: signature_.ordinal,
+        medal_token_id: signature_.medal_token_id,
+        version: signature_.version,
+        block_height: signature_.block_height,
+        signed_at: signature_.signed_at,
+        withdrawn_at: signature_.withdrawn_at,
+    }
+}
+
+/// Verifies that the funds sent with a new signature pay the signing fee, in a single accepted
+/// denom. Returns the message forwarding them to the treasury, net of the Terra stability tax,
+/// along with the fee paid
+fn collect_signing_fee(
+    deps: Deps,
+    config: &Config,
+    funds: Vec<Coin>,
+) -> Result<Option<(CosmosMsg, Coin)>, ContractError> {
+    let signing_fee = match &config.signing_fee {
+        Some(signing_fee) => signing_fee,
+        None if funds.is_empty() => return Ok(None),
+        None => return Err(ContractError::FundsNotRequired {}),
+    };
+
+    let paid = match funds.as_slice() {
+        [] => return Err(ContractError::MissingFee {}),
+        [paid] => paid,
+        _ => return Err(ContractError::MultipleFeeDenoms {}),
+    };
+    let required = signing_fee
+        .amounts
+        .iter()
+        .find(|coin| coin.denom == paid.denom)
+        .ok_or_else(|| ContractError::UnacceptedDenom {
+            denom: paid.denom.clone(),
+        })?;
+    if paid.amount < required.amount {
+        return Err(ContractError::InsufficientFee {
+            denom: paid.denom.clone(),
+            required: required.amount,
+            sent: paid.amount,
+        });
+    }
+
+    let fee_msg = CosmosMsg::Bank(BankMsg::Send {
+        to_address: signing_fee.treasury.to_string(),
+        amount: vec![deduct_tax(&deps.querier, paid.clone())?],
+    });
+    Ok(Some((fee_msg, paid.clone())))
+}
+
+/// Returns the SubMsg minting the MEDAL of a signature, whose reply confirms the mint. The
+/// signature is stored as pending until the reply is handled
+fn mint_medal(
+    deps: DepsMut,
+    manifesto: &Manifesto,
+    signature_: &Signature,
+) -> Result<SubMsg, ContractError> {
+    let medal_mint_msg =
+        build_medal_mint_msg(deps.as_ref(), manifesto.medal_addr.to_string(), signature_)?;
+    let mut pending_mints = PENDING_MINTS.may_load(deps.storage)?.unwrap_or_default();
+    pending_mints.push(PendingMint {
+        manifesto_id: manifesto.id,
+        signee: signature_.signee.clone(),
+    });
+    PENDING_MINTS.save(deps.storage, &pending_mints)?;
+
+    Ok(SubMsg::reply_always(medal_mint_msg, MINT_MEDAL_REPLY_ID))
+}
+
+/// Helper Function. Returns CosmosMsg which updates MEDAL (Redeem) address in the MEDAL Contract
+pub fn build_update_medal_redeem_addr_msg(
+    medal_addr: String,
+    medal_redeem_addr: String,
+    metadata: MedalMetaData,
+) -> StdResult<CosmosMsg> {
+    Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: medal_addr,
+        msg: to_binary(&MedalExecuteMsg::UpdateMedalRedeemConfig {
+            medal_redeem_addr,
+            metadata,
+        })?,
+        funds: vec![],
+    }))
+}
+
+/// Helper Function. Returns CosmosMsg which burns the MEDAL of a Signee withdrawing their signature
+pub fn build_medal_burn_msg(
+    medal_addr: String,
+    token_id: String,
+    owner: String,
+) -> StdResult<CosmosMsg> {
+    Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: medal_addr,
+        msg: to_binary(&MedalExecuteMsg::BurnMedal { token_id, owner })?,
+        funds: vec![],
+    }))
+}
+
+/// Helper Function. Returns CosmosMsg which updates the SVG template of the MEDAL Contract
+pub fn build_update_medal_svg_template_msg(
+    medal_addr: String,
+    template: Option<String>,
+) -> StdResult<CosmosMsg> {
+    Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: medal_addr,
+        msg: to_binary(&MedalExecuteMsg::UpdateSvgTemplate { template })?,
+        funds: vec![],
+    }))
+}
+
+/// Helper Function. Returns CosmosMsg which updates the redemption fee of the MEDAL Contract
+pub fn build_update_medal_redemption_fee_msg(
+    medal_addr: String,
+    recipient: String,
+    regions: Vec<RegionFee>,
+) -> StdResult<CosmosMsg> {
+    Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: medal_addr,
+        msg: to_binary(&MedalExecuteMsg::UpdateRedemptionFee { recipient, regions })?,
+        funds: vec![],
+    }))
+}
+
+/// Helper Function. Returns CosmosMsg which mints a MEDAL to the Signee
+pub fn build_medal_mint_msg(
+    deps: Deps,
+    medal_addr: String,
+    signature_: &Signature,
+) -> StdResult<CosmosMsg> {
+    let manifesto_id = signature_.manifesto_id;
+    let token_id = signature_.ordinal;
+    let template = load_medal_template(deps, manifesto_id)?;
+
+    // MEDALs of a tier use its metadata
+    let tier = load_medal_tier(deps, manifesto_id, signature_.ordinal)?;
+    let metadata = match &tier {
+        Some(tier) => tier.metadata.clone(),
+        None => METADATA.load(deps.storage, U64Key::new(manifesto_id))?,
+    };
+
+    // Signing phase the signature was recorded in
+    let phases = load_signing_phases(deps.storage, manifesto_id)?;
+    let signing_block = BlockInfo {
+        height: signature_.block_height,
+        time: signature_.signed_at,
+        chain_id: String::new(),
+    };
+
+    let mut attributes_vec = vec![];
+    for trait_ in template.traits {
+        let value = match trait_.value {
+            TraitValue::Static(value) => value,
+            TraitValue::Placeholder(placeholder) => match placeholder {
+                TraitPlaceholder::Ordinal => signature_.ordinal.to_string(),
+                TraitPlaceholder::BlockHeight => signature_.block_height.to_string(),
+                TraitPlaceholder::EarthDate => signature_.signed_at.seconds().to_string(),
+                TraitPlaceholder::MartianDate => signature_.martian_date.clone(),
+                TraitPlaceholder::MartianTime => signature_.martian_time.clone(),
+                TraitPlaceholder::DarianMonth => darian::from_timestamp(signature_.signed_at)
+                    .0
+                    .month_name()
+                    .to_string(),
+                TraitPlaceholder::SigningPhase => {
+                    signing_round(&phases, &signing_block).to_string()
+                }
+            },
+        };
+        attributes_vec.push(Trait {
+            display_type: trait_
+                .display_type
+                .map(|display_type| display_type.to_string()),
+            trait_type: trait_.trait_type,
+            value,
+        });
+    }
+    if let Some(tier) = tier {
+        attributes_vec.push(Trait {
+            display_type: None,
+            trait_type: "tier".to_string(),
+            value: tier.name,
+        });
+    }
 
     let extension_ = Metadata {
         image: Some(metadata.image.clone()),
         image_data: None,
         external_url: None,
         description: Some(metadata.description.clone()),
-        name: Some(metadata.name_prefix.clone() + &" #".to_string() + &token_id.to_string()),
+        name: Some(metadata.name_prefix.clone() + " #" + &token_id.to_string()),
         attributes: Some(attributes_vec),
-        background_color: None,
-        animation_url: None,
+        background_color: template.background_color,
+        animation_url: template.animation_url,
         youtube_url: None,
     };
 
     let mint_msg = MintMsg {
         token_id: token_id.to_string(),
-        owner: user_addr,
-        name: metadata.name_prefix + &" #".to_string() + &token_id.to_string(),
+        owner: signature_.signee.to_string(),
+        name: metadata.name_prefix + " #" + &token_id.to_string(),
         description: Some(metadata.description),
         image: Some(metadata.token_uri),
         extension: extension_,
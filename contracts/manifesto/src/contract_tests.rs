@@ -0,0 +1,3376 @@
+#![cfg(test)]
+use cosmwasm_std::testing::{
+    mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage,
+};
+use cosmwasm_std::{
+    attr, coin, from_binary, from_slice, to_binary, Addr, BankMsg, Coin, ContractResult, CosmosMsg,
+    Decimal, Deps, DepsMut, Empty, Event, OwnedDeps, Querier, QuerierResult, QuerierWrapper,
+    QueryRequest, Reply, ReplyOn, StdError, SubMsgExecutionResponse, SystemResult, Timestamp,
+    WasmMsg, WasmQuery,
+};
+
+use crate::contract::{
+    execute, instantiate, migrate, query, reply, CONTRACT_NAME, CONTRACT_VERSION,
+    DEFAULT_TIME_TOLERANCE,
+};
+use crate::error::ContractError;
+use crate::migrations::v1_0_0;
+use bech32::{ToBase32, Variant};
+use cw2::{get_contract_version, set_contract_version, ContractVersion};
+use cw20::Expiration;
+use cw721::{Cw721QueryMsg, NftInfoResponse};
+use k256::ecdsa::signature::Signer;
+use k256::ecdsa::{Signature, SigningKey, VerifyingKey};
+use mars_community::darian::{self, DarianError};
+use mars_community::events::{self, ManifestoSignData, MedalMintData};
+use mars_community::tax::deduct_tax;
+use sha2::Digest;
+use terra_cosmwasm::{TaxCapResponse, TaxRateResponse, TerraQuery, TerraQueryWrapper};
+
+use mars_community::manifesto::{
+    AdminProposalResponse, ConfigResponse, DisplayType, EligibilityResponse, ExecuteMsg,
+    ImportedSignature, InstantiateMsg, LegacyMedal, ManifestoResponse, ManifestoVersionResponse,
+    ManifestoVersionsResponse, ManifestosResponse, MedalExecuteMsg, MedalMetaData, MedalTemplate,
+    MedalTier, MedalTiersResponse, MigrateMsg, MonthCount, NonceResponse, OrderBy, PhaseBoundary,
+    PhasesResponse, QueryMsg, SignManifestoPayload, SignatureAtHeightResponse,
+    SignatureHistogramResponse, SignatureResponse, SignaturesResponse, SigneeResponse,
+    SigneesResponse, SigningFee, SigningPhase, SolCount, StateResponse, TraitPlaceholder,
+    TraitTemplate, TraitValue,
+};
+use mars_community::metadata::{Metadata, Trait};
+use mars_community::pause::{PauseError, PauseFlag, PausedResponse};
+
+const ADMIN: &str = "admin";
+const MEDAL: &str = "medal";
+const MEDAL_REDEEM: &str = "medal_redeem";
+const MAX_SIGNEES: u64 = 5;
+const MANIFESTO_ID: u64 = 1;
+const CONTENT_HASH: &str = "8f434346648f6b96df89dda901c5176b10a6d83961dd3c1ac88b59b2dc327aa4";
+
+// Martian date and time of `mock_env().block.time`
+const MARTIAN_DATE: &str = "14 Mina 218";
+const MARTIAN_TIME: &str = "15:40:34 MTC";
+
+fn setup_contract(mut deps: DepsMut) {
+    let msg = InstantiateMsg {
+        admin: ADMIN.to_string(),
+        time_tolerance: None,
+    };
+    instantiate(deps.branch(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+
+    let msg = ExecuteMsg::RegisterManifesto {
+        title: "Mars Manifesto".to_string(),
+        content_hash: CONTENT_HASH.to_string(),
+        uri: None,
+        max_signees_limit: MAX_SIGNEES,
+        medal_addr: Some(MEDAL.to_string()),
+        medal_redeem_addr: Some(MEDAL_REDEEM.to_string()),
+    };
+    execute(deps.branch(), mock_env(), mock_info(ADMIN, &[]), msg).unwrap();
+
+    let msg = ExecuteMsg::UpdateMedalConfig {
+        manifesto_id: MANIFESTO_ID,
+        medal_addr: MEDAL.to_string(),
+        metadata: Metadata {
+            name: Some("MEDAL".to_string()),
+            description: Some("A badge of honor for the earliest Martians".to_string()),
+            image: Some("ipfs://medal".to_string()),
+            external_url: Some("ipfs://medal_token_uri".to_string()),
+            ..Metadata::default()
+        },
+    };
+    execute(deps, mock_env(), mock_info(ADMIN, &[]), msg).unwrap();
+}
+
+/// Dependencies answering Terra tax queries with a 0.1% rate and a cap of `tax_cap`
+fn mock_dependencies_with_tax(
+    tax_cap: u128,
+) -> OwnedDeps<MockStorage, MockApi, MockQuerier<TerraQueryWrapper>> {
+    let querier = MockQuerier::new(&[]).with_custom_handler(move |query: &TerraQueryWrapper| {
+        let res = match &query.query_data {
+            TerraQuery::TaxRate {} => to_binary(&TaxRateResponse {
+                rate: Decimal::permille(1),
+            }),
+            TerraQuery::TaxCap { .. } => to_binary(&TaxCapResponse {
+                cap: tax_cap.into(),
+            }),
+            q => panic!("unexpected terra query: {:?}", q),
+        };
+        SystemResult::Ok(ContractResult::Ok(res.unwrap()))
+    });
+    OwnedDeps {
+        storage: MockStorage::default(),
+        api: MockApi::default(),
+        querier,
+    }
+}
+
+/// Answers MEDAL queries as if v1.0.0 minted MEDAL 1 to "cydonia", 2 to "borealis" and 3 to
+/// "aurora", the latter since redeemed
+struct LegacyMedals;
+
+impl Querier for LegacyMedals {
+    fn raw_query(&self, bin_request: &[u8]) -> QuerierResult {
+        let msg = match from_slice(bin_request).unwrap() {
+            QueryRequest::<Empty>::Wasm(WasmQuery::Smart { contract_addr, msg })
+                if contract_addr == MEDAL =>
+            {
+                msg
+            }
+            q => panic!("unexpected query: {:?}", q),
+        };
+        let (martian_date, martian_time) = match from_binary(&msg).unwrap() {
+            Cw721QueryMsg::NftInfo { token_id } if token_id == "1" => {
+                ("13 Mina 218", "23:59:59 MTC")
+            }
+            Cw721QueryMsg::NftInfo { token_id } if token_id == "2" => {
+                ("14 Mina 218", "15:40:34 MTC")
+            }
+            Cw721QueryMsg::NftInfo { .. } => {
+                return SystemResult::Ok(ContractResult::Err("Token not found".to_string()))
+            }
+            q => panic!("unexpected MEDAL query: {:?}", q),
+        };
+        let traits = [
+            ("martian_date", martian_date),
+            ("martian_time", martian_time),
+        ]
+        .iter()
+        .map(|(trait_type, value)| Trait {
+            display_type: None,
+            trait_type: trait_type.to_string(),
+            value: value.to_string(),
+        })
+        .collect();
+        SystemResult::Ok(ContractResult::Ok(
+            to_binary(&NftInfoResponse {
+                token_uri: None,
+                extension: Some(Metadata {
+                    attributes: Some(traits),
+                    ..Metadata::default()
+                }),
+            })
+            .unwrap(),
+        ))
+    }
+}
+
+fn sign_msg(martian_date: &str, martian_time: &str) -> ExecuteMsg {
+    ExecuteMsg::SignManifesto {
+        manifesto_id: MANIFESTO_ID,
+        content_hash: CONTENT_HASH.to_string(),
+        martian_date: Some(martian_date.to_string()),
+        martian_time: Some(martian_time.to_string()),
+        proof: None,
+    }
+}
+
+/// Reply of the MEDAL contract to a successful mint, with its data wrapped in a
+/// `MsgExecuteContractResponse` as done by the chain
+fn mint_reply(token_id: &str) -> Reply {
+    let mint_data = to_binary(&MedalMintData {
+        token_id: token_id.to_string(),
+    })
+    .unwrap();
+    let mut data = vec![0x0a, mint_data.len() as u8];
+    data.extend_from_slice(mint_data.as_slice());
+    Reply {
+        id: 1,
+        result: ContractResult::Ok(SubMsgExecutionResponse {
+            events: vec![Event::new("wasm")
+                .add_attribute("_contract_address", MEDAL)
+                .add_attribute("action", "mint")],
+            data: Some(data.into()),
+        }),
+    }
+}
+
+fn query_signature(deps: Deps, signee: &str) -> SignatureResponse {
+    let res = query(
+        deps,
+        mock_env(),
+        QueryMsg::GetSignature {
+            manifesto_id: MANIFESTO_ID,
+            signee: signee.to_string(),
+        },
+    )
+    .unwrap();
+    from_binary(&res).unwrap()
+}
+
+#[test]
+fn mock_env_martian_date_and_time() {
+    let (date, time) = darian::from_timestamp(mock_env().block.time);
+    assert_eq!(MARTIAN_DATE, date.to_string());
+    assert_eq!(MARTIAN_TIME, time.to_string());
+}
+
+#[test]
+fn signing_manifesto() {
+    let mut deps = mock_dependencies(&[]);
+    setup_contract(deps.as_mut());
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("signee", &[]),
+        sign_msg(MARTIAN_DATE, MARTIAN_TIME),
+    )
+    .unwrap();
+    assert_eq!(1, res.messages.len());
+    assert_eq!(
+        res.events,
+        vec![events::manifesto_sign_event(
+            CONTRACT_VERSION,
+            MANIFESTO_ID,
+            &Addr::unchecked("signee"),
+            1,
+            MARTIAN_DATE
+        )]
+    );
+    let data: ManifestoSignData = from_binary(&res.data.unwrap()).unwrap();
+    assert_eq!(
+        data,
+        ManifestoSignData {
+            manifesto_id: MANIFESTO_ID,
+            ordinal: 1,
+        }
+    );
+
+    let signature = query_signature(deps.as_ref(), "signee");
+    assert_eq!(
+        signature,
+        SignatureResponse {
+            manifesto_id: MANIFESTO_ID,
+            signee: "signee".to_string(),
+            martian_date: MARTIAN_DATE.to_string(),
+            martian_time: MARTIAN_TIME.to_string(),
+            ordinal: 1,
+            medal_token_id: None,
+            version: 1,
+            block_height: mock_env().block.height,
+            signed_at: mock_env().block.time,
+            withdrawn_at: None,
+        }
+    );
+
+    // cannot sign twice
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("signee", &[]),
+        sign_msg(MARTIAN_DATE, MARTIAN_TIME),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::AlreadySigned {});
+}
+
+#[test]
+fn rejects_invalid_martian_date_and_time() {
+    let mut deps = mock_dependencies(&[]);
+    setup_contract(deps.as_mut());
+
+    let cases = [
+        (
+            "aaaaaaaaaaaa",
+            MARTIAN_TIME,
+            DarianError::MalformedDate {
+                value: "aaaaaaaaaaaa".to_string(),
+            },
+        ),
+        (
+            "28 Kumbha 219",
+            MARTIAN_TIME,
+            DarianError::SolOutOfRange {
+                sol: 28,
+                month: "Kumbha".to_string(),
+                year: 219,
+                max: 27,
+            },
+        ),
+        (
+            "26 Terra 219",
+            MARTIAN_TIME,
+            DarianError::UnknownMonth {
+                month: "Terra".to_string(),
+            },
+        ),
+        (
+            MARTIAN_DATE,
+            "aaaaaaaaaaaa",
+            DarianError::MalformedTime {
+                value: "aaaaaaaaaaaa".to_string(),
+            },
+        ),
+        (
+            MARTIAN_DATE,
+            "25:05:33 MTC",
+            DarianError::TimeOutOfRange {
+                hours: 25,
+                minutes: 5,
+                seconds: 33,
+            },
+        ),
+    ];
+
+    for (martian_date, martian_time, expected) in cases {
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("signee", &[]),
+            sign_msg(martian_date, martian_time),
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Darian(expected));
+    }
+}
+
+#[test]
+fn derives_martian_date_and_time_from_block_time() {
+    let mut deps = mock_dependencies(&[]);
+    setup_contract(deps.as_mut());
+
+    // Signee doesn't send a Martian date and time
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(88_775);
+    let msg = ExecuteMsg::SignManifesto {
+        manifesto_id: MANIFESTO_ID,
+        content_hash: CONTENT_HASH.to_string(),
+        martian_date: None,
+        martian_time: None,
+        proof: None,
+    };
+    let res = execute(deps.as_mut(), env.clone(), mock_info("signee", &[]), msg).unwrap();
+
+    let (date, time) = darian::from_timestamp(env.block.time);
+    assert_eq!("15 Mina 218", date.to_string());
+
+    let signature = query_signature(deps.as_ref(), "signee");
+    assert_eq!(date.to_string(), signature.martian_date);
+    assert_eq!(time.to_string(), signature.martian_time);
+
+    // Medal traits carry the same values
+    let expected_traits = vec![
+        Trait {
+            display_type: None,
+            trait_type: "martian_date".to_string(),
+            value: date.to_string(),
+        },
+        Trait {
+            display_type: None,
+            trait_type: "martian_time".to_string(),
+            value: time.to_string(),
+        },
+    ];
+    match &res.messages[0].msg {
+        CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) => match from_binary(msg).unwrap() {
+            MedalExecuteMsg::Mint(mint_msg) => {
+                assert_eq!(Some(expected_traits), mint_msg.extension.attributes)
+            }
+            m => panic!("unexpected medal msg: {:?}", m),
+        },
+        m => panic!("unexpected msg: {:?}", m),
+    }
+
+    // Only one of date and time
+    let msg = ExecuteMsg::SignManifesto {
+        manifesto_id: MANIFESTO_ID,
+        content_hash: CONTENT_HASH.to_string(),
+        martian_date: Some(MARTIAN_DATE.to_string()),
+        martian_time: None,
+        proof: None,
+    };
+    let err = execute(deps.as_mut(), mock_env(), mock_info("other", &[]), msg).unwrap_err();
+    assert_eq!(err, ContractError::IncompleteMartianDateTime {});
+}
+
+#[test]
+fn rejects_martian_date_and_time_drifting_from_block_time() {
+    let mut deps = mock_dependencies(&[]);
+    setup_contract(deps.as_mut());
+
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(DEFAULT_TIME_TOLERANCE + 10);
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("signee", &[]),
+        sign_msg(MARTIAN_DATE, MARTIAN_TIME),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::MartianDateTimeDrift {
+            drift: DEFAULT_TIME_TOLERANCE + 10,
+            time_tolerance: DEFAULT_TIME_TOLERANCE,
+        }
+    );
+
+    // Only admin can update the tolerance
+    let msg = ExecuteMsg::UpdateTimeTolerance {
+        time_tolerance: 3_600,
+    };
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("signee", &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    execute(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]), msg).unwrap();
+
+    let res = query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap();
+    let config: ConfigResponse = from_binary(&res).unwrap();
+    assert_eq!(3_600, config.time_tolerance);
+
+    // Signature records the block time values, not the ones sent by the signee
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("signee", &[]),
+        sign_msg(MARTIAN_DATE, MARTIAN_TIME),
+    )
+    .unwrap();
+    let (_, time) = darian::from_timestamp(env.block.time);
+    let signature = query_signature(deps.as_ref(), "signee");
+    assert_eq!(time.to_string(), signature.martian_time);
+    assert_ne!(MARTIAN_TIME, signature.martian_time);
+}
+
+#[test]
+fn querying_signatures_in_signing_order() {
+    let mut deps = mock_dependencies(&[]);
+    setup_contract(deps.as_mut());
+
+    let signees = ["mercury", "venus", "earth", "mars"];
+    for (i, signee) in signees.iter().enumerate() {
+        let mut env = mock_env();
+        env.block.height += i as u64;
+        let msg = ExecuteMsg::SignManifesto {
+            manifesto_id: MANIFESTO_ID,
+            content_hash: CONTENT_HASH.to_string(),
+            martian_date: None,
+            martian_time: None,
+            proof: None,
+        };
+        execute(deps.as_mut(), env, mock_info(signee, &[]), msg).unwrap();
+    }
+
+    // by ordinal
+    let res = query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::SignatureByOrdinal {
+            manifesto_id: MANIFESTO_ID,
+            ordinal: 3,
+        },
+    )
+    .unwrap();
+    let signature: SignatureResponse = from_binary(&res).unwrap();
+    assert_eq!("earth", signature.signee);
+    assert_eq!(None, signature.medal_token_id);
+    assert_eq!(mock_env().block.height + 2, signature.block_height);
+
+    let err = query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::SignatureByOrdinal {
+            manifesto_id: MANIFESTO_ID,
+            ordinal: 5,
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::Std(StdError::NotFound { .. })));
+
+    // paginated, ascending by default
+    let query_signees = |start_after: Option<u64>, limit: Option<u32>, order: Option<OrderBy>| {
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::AllSignatures {
+                manifesto_id: MANIFESTO_ID,
+                start_after,
+                limit,
+                order,
+            },
+        )
+        .unwrap();
+        let res: SignaturesResponse = from_binary(&res).unwrap();
+        res.signatures
+            .into_iter()
+            .map(|s| (s.ordinal, s.signee))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        vec![(1, "mercury".to_string()), (2, "venus".to_string())],
+        query_signees(None, Some(2), None)
+    );
+    assert_eq!(
+        vec![(3, "earth".to_string()), (4, "mars".to_string())],
+        query_signees(Some(2), None, Some(OrderBy::Asc))
+    );
+    assert_eq!(
+        vec![(4, "mars".to_string()), (3, "earth".to_string())],
+        query_signees(None, Some(2), Some(OrderBy::Desc))
+    );
+    assert_eq!(
+        vec![(2, "venus".to_string()), (1, "mercury".to_string())],
+        query_signees(Some(3), Some(2), Some(OrderBy::Desc))
+    );
+}
+
+#[test]
+fn signing_phases() {
+    let mut deps = mock_dependencies(&[]);
+    setup_contract(deps.as_mut());
+
+    let height = mock_env().block.height;
+    let time = mock_env().block.time;
+    let next_sol =
+        darian::MartianDate::from_mars_sol_date(darian::mars_sol_date(time) + 1).unwrap();
+    let phases = vec![
+        SigningPhase {
+            id: 1,
+            start: PhaseBoundary::AtHeight(height + 10),
+            end: PhaseBoundary::AtHeight(height + 20),
+            max_signees: 1,
+        },
+        SigningPhase {
+            id: 2,
+            start: PhaseBoundary::AtTime(time.plus_seconds(3_600)),
+            end: PhaseBoundary::AtMartianDate(next_sol),
+            max_signees: 10,
+        },
+    ];
+
+    // Only admin can update phases
+    let msg = ExecuteMsg::UpdateSigningPhases {
+        manifesto_id: MANIFESTO_ID,
+        phases: phases.clone(),
+    };
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("signee", &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    // Phase ids must be unique
+    let mut duplicates = phases.clone();
+    duplicates[1].id = 1;
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        ExecuteMsg::UpdateSigningPhases {
+            manifesto_id: MANIFESTO_ID,
+            phases: duplicates,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::DuplicatePhaseId { phase_id: 1 });
+
+    // Phases must end after they start
+    let inverted_windows = vec![
+        (
+            PhaseBoundary::AtHeight(height + 10),
+            PhaseBoundary::AtHeight(height + 10),
+        ),
+        (
+            PhaseBoundary::AtTime(time.plus_seconds(3_600)),
+            PhaseBoundary::AtTime(time),
+        ),
+        (
+            PhaseBoundary::AtMartianDate(next_sol),
+            PhaseBoundary::AtTime(time),
+        ),
+    ];
+    for (start, end) in inverted_windows {
+        let mut inverted = phases.clone();
+        inverted[1].start = start;
+        inverted[1].end = end;
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ADMIN, &[]),
+            ExecuteMsg::UpdateSigningPhases {
+                manifesto_id: MANIFESTO_ID,
+                phases: inverted,
+            },
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::InvalidPhaseWindow { phase_id: 2 });
+    }
+
+    // Phases are set per registered manifesto
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        ExecuteMsg::UpdateSigningPhases {
+            manifesto_id: 2,
+            phases: phases.clone(),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::ManifestoNotFound { manifesto_id: 2 });
+
+    execute(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]), msg).unwrap();
+
+    let sign = ExecuteMsg::SignManifesto {
+        manifesto_id: MANIFESTO_ID,
+        content_hash: CONTENT_HASH.to_string(),
+        martian_date: None,
+        martian_time: None,
+        proof: None,
+    };
+    let query_phases = |deps: Deps, env| -> PhasesResponse {
+        from_binary(
+            &query(
+                deps,
+                env,
+                QueryMsg::Phases {
+                    manifesto_id: MANIFESTO_ID,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    };
+
+    // Before the first phase
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("signee", &[]),
+        sign.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::NoActivePhase {});
+    let res = query_phases(deps.as_ref(), mock_env());
+    assert_eq!(None, res.current);
+    assert_eq!(
+        vec![1, 2],
+        res.upcoming.iter().map(|p| p.phase.id).collect::<Vec<_>>()
+    );
+
+    // During the first phase
+    let mut env = mock_env();
+    env.block.height = height + 10;
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("signee", &[]),
+        sign.clone(),
+    )
+    .unwrap();
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("other", &[]),
+        sign.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::PhaseMaxSigneesReached {
+            phase_id: 1,
+            signees_count: 1,
+            max_signees: 1,
+        }
+    );
+    let res = query_phases(deps.as_ref(), env);
+    let current = res.current.unwrap();
+    assert_eq!((1, 1), (current.phase.id, current.signees_count));
+    assert_eq!(
+        vec![2],
+        res.upcoming.iter().map(|p| p.phase.id).collect::<Vec<_>>()
+    );
+
+    // During the second phase
+    let mut env = mock_env();
+    env.block.height = height + 30;
+    env.block.time = time.plus_seconds(3_600);
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("other", &[]),
+        sign.clone(),
+    )
+    .unwrap();
+    let res = query_phases(deps.as_ref(), env);
+    let current = res.current.unwrap();
+    assert_eq!((2, 1), (current.phase.id, current.signees_count));
+    assert!(res.upcoming.is_empty());
+
+    // Second phase ends at the start of the next sol
+    let mut env = mock_env();
+    env.block.height = height + 30;
+    env.block.time = darian::to_timestamp(&next_sol, &darian::MartianTime::new(0, 0, 0).unwrap())
+        .unwrap()
+        .plus_seconds(1);
+    let err = execute(deps.as_mut(), env, mock_info("third", &[]), sign).unwrap_err();
+    assert_eq!(err, ContractError::NoActivePhase {});
+}
+
+fn sha256(data: &[u8]) -> [u8; 32] {
+    sha2::Sha256::digest(data).into()
+}
+
+fn hash_pair(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    sha256(&[first, second].concat())
+}
+
+#[test]
+fn allowlisted_signing_rounds() {
+    let mut deps = mock_dependencies(&[]);
+    setup_contract(deps.as_mut());
+
+    // Merkle tree of 4 addresses
+    let leaves: Vec<[u8; 32]> = ["mercury", "venus", "earth", "mars"]
+        .iter()
+        .map(|address| sha256(address.as_bytes()))
+        .collect();
+    let left = hash_pair(leaves[0], leaves[1]);
+    let right = hash_pair(leaves[2], leaves[3]);
+    let root = hex::encode(hash_pair(left, right));
+    let venus_proof = vec![hex::encode(leaves[0]), hex::encode(right)];
+
+    // Only admin can set the root, which must be a valid hash
+    let msg = ExecuteMsg::UpdateMerkleRoot {
+        manifesto_id: MANIFESTO_ID,
+        round: 0,
+        merkle_root: Some(root.clone()),
+    };
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("venus", &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        ExecuteMsg::UpdateMerkleRoot {
+            manifesto_id: MANIFESTO_ID,
+            round: 0,
+            merkle_root: Some("abcd".to_string()),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidHash {
+            hash: "abcd".to_string()
+        }
+    );
+    execute(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]), msg).unwrap();
+
+    let sign = |proof: Option<Vec<String>>| ExecuteMsg::SignManifesto {
+        manifesto_id: MANIFESTO_ID,
+        content_hash: CONTENT_HASH.to_string(),
+        martian_date: None,
+        martian_time: None,
+        proof,
+    };
+    let is_eligible = |deps: Deps, address: &str, proof: Option<Vec<String>>| {
+        let res = query(
+            deps,
+            mock_env(),
+            QueryMsg::IsEligible {
+                manifesto_id: MANIFESTO_ID,
+                address: address.to_string(),
+                proof,
+                round: None,
+            },
+        )
+        .unwrap();
+        let res: EligibilityResponse = from_binary(&res).unwrap();
+        assert_eq!(0, res.round);
+        res.is_eligible
+    };
+
+    assert!(is_eligible(
+        deps.as_ref(),
+        "venus",
+        Some(venus_proof.clone())
+    ));
+    assert!(!is_eligible(
+        deps.as_ref(),
+        "pluto",
+        Some(venus_proof.clone())
+    ));
+    assert!(!is_eligible(deps.as_ref(), "venus", None));
+
+    // Proof is required
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("venus", &[]),
+        sign(None),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::MerkleProofRequired { round: 0 });
+
+    // Proof of another address is rejected
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("pluto", &[]),
+        sign(Some(venus_proof.clone())),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::NotEligible { round: 0 });
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("venus", &[]),
+        sign(Some(venus_proof)),
+    )
+    .unwrap();
+
+    // Rounds follow signing phases, and rounds without a root are open to everyone
+    let phases = vec![SigningPhase {
+        id: 7,
+        start: PhaseBoundary::AtHeight(0),
+        end: PhaseBoundary::AtHeight(u64::MAX),
+        max_signees: 10,
+    }];
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        ExecuteMsg::UpdateSigningPhases {
+            manifesto_id: MANIFESTO_ID,
+            phases,
+        },
+    )
+    .unwrap();
+    let res: EligibilityResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::IsEligible {
+                manifesto_id: MANIFESTO_ID,
+                address: "pluto".to_string(),
+                proof: None,
+                round: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        res,
+        EligibilityResponse {
+            round: 7,
+            merkle_root: None,
+            is_eligible: true,
+        }
+    );
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("pluto", &[]),
+        sign(None),
+    )
+    .unwrap();
+}
+
+#[test]
+fn multiple_manifestos() {
+    let mut deps = mock_dependencies(&[]);
+    setup_contract(deps.as_mut());
+
+    // Only admin can register a manifesto, whose content hash must be a valid hash
+    let msg = ExecuteMsg::RegisterManifesto {
+        title: "Martian Constitution".to_string(),
+        content_hash: CONTENT_HASH.to_string(),
+        uri: None,
+        max_signees_limit: 1,
+        medal_addr: Some("constitution_medal".to_string()),
+        medal_redeem_addr: None,
+    };
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("signee", &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        ExecuteMsg::RegisterManifesto {
+            title: "Martian Constitution".to_string(),
+            content_hash: "abcd".to_string(),
+            uri: None,
+            max_signees_limit: 1,
+            medal_addr: None,
+            medal_redeem_addr: None,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidHash {
+            hash: "abcd".to_string()
+        }
+    );
+    let res = execute(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]), msg).unwrap();
+    assert_eq!("2", res.attributes[1].value);
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        ExecuteMsg::UpdateMedalConfig {
+            manifesto_id: 2,
+            medal_addr: "constitution_medal".to_string(),
+            metadata: Metadata {
+                name: Some("Constitution MEDAL".to_string()),
+                ..Metadata::default()
+            },
+        },
+    )
+    .unwrap();
+
+    let sign = |manifesto_id: u64| ExecuteMsg::SignManifesto {
+        manifesto_id,
+        content_hash: CONTENT_HASH.to_string(),
+        martian_date: None,
+        martian_time: None,
+        proof: None,
+    };
+
+    // The same signee can sign both manifestos, each minting its own MEDAL
+    execute(deps.as_mut(), mock_env(), mock_info("signee", &[]), sign(1)).unwrap();
+    let res = execute(deps.as_mut(), mock_env(), mock_info("signee", &[]), sign(2)).unwrap();
+    match &res.messages[0].msg {
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr, msg, ..
+        }) => {
+            assert_eq!("constitution_medal", contract_addr);
+            match from_binary(msg).unwrap() {
+                MedalExecuteMsg::Mint(mint_msg) => {
+                    assert_eq!("1", mint_msg.token_id);
+                    assert_eq!("Constitution MEDAL #1", mint_msg.name);
+                }
+                m => panic!("unexpected medal msg: {:?}", m),
+            }
+        }
+        m => panic!("unexpected msg: {:?}", m),
+    }
+
+    // Signee limits are per manifesto
+    let err = execute(deps.as_mut(), mock_env(), mock_info("other", &[]), sign(2)).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::MaxSigneesReached {
+            signees_count: 1,
+            max_signees_allowed: 1,
+        }
+    );
+    execute(deps.as_mut(), mock_env(), mock_info("other", &[]), sign(1)).unwrap();
+
+    let err = execute(deps.as_mut(), mock_env(), mock_info("other", &[]), sign(3)).unwrap_err();
+    assert_eq!(err, ContractError::ManifestoNotFound { manifesto_id: 3 });
+
+    // Queries are scoped per manifesto
+    let res = query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::State { manifesto_id: 1 },
+    )
+    .unwrap();
+    let state: StateResponse = from_binary(&res).unwrap();
+    assert_eq!(2, state.signee_count);
+    let err = query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::GetSignature {
+            manifesto_id: 2,
+            signee: "other".to_string(),
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::Std(StdError::NotFound { .. })));
+
+    // Admin can raise the limit of a manifesto
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        ExecuteMsg::UpdateManifesto {
+            manifesto_id: 2,
+            title: None,
+            max_signees_limit: Some(2),
+        },
+    )
+    .unwrap();
+    execute(deps.as_mut(), mock_env(), mock_info("other", &[]), sign(2)).unwrap();
+
+    let res = query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::Manifestos {
+            start_after: Some(1),
+            limit: None,
+        },
+    )
+    .unwrap();
+    let res: ManifestosResponse = from_binary(&res).unwrap();
+    assert_eq!(
+        res.manifestos,
+        vec![ManifestoResponse {
+            id: 2,
+            title: "Martian Constitution".to_string(),
+            version: 1,
+            content_hash: CONTENT_HASH.to_string(),
+            uri: None,
+            max_signees_allowed: 2,
+            medal_addr: Addr::unchecked("constitution_medal"),
+            medal_redeem_addr: Addr::unchecked(""),
+            signee_count: 2,
+        }]
+    );
+
+    let res = query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap();
+    let config: ConfigResponse = from_binary(&res).unwrap();
+    assert_eq!(2, config.manifestos_count);
+    // Signing phases and allowlists are per manifesto
+    let height = mock_env().block.height;
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        ExecuteMsg::UpdateSigningPhases {
+            manifesto_id: 2,
+            phases: vec![SigningPhase {
+                id: 1,
+                start: PhaseBoundary::AtHeight(height + 10),
+                end: PhaseBoundary::AtHeight(height + 20),
+                max_signees: 1,
+            }],
+        },
+    )
+    .unwrap();
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        ExecuteMsg::UpdateMerkleRoot {
+            manifesto_id: 1,
+            round: 0,
+            merkle_root: Some(CONTENT_HASH.to_string()),
+        },
+    )
+    .unwrap();
+    let err = execute(deps.as_mut(), mock_env(), mock_info("third", &[]), sign(1)).unwrap_err();
+    assert_eq!(err, ContractError::MerkleProofRequired { round: 0 });
+    let phases = |manifesto_id: u64| -> PhasesResponse {
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Phases { manifesto_id }).unwrap())
+            .unwrap()
+    };
+    assert!(phases(1).upcoming.is_empty());
+    assert_eq!(1, phases(2).upcoming.len());
+    let is_eligible = |manifesto_id: u64| -> bool {
+        let res: EligibilityResponse = from_binary(
+            &query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::IsEligible {
+                    manifesto_id,
+                    address: "third".to_string(),
+                    proof: None,
+                    round: None,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        res.is_eligible
+    };
+    assert!(!is_eligible(1));
+    assert!(is_eligible(2));
+    let err = query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::Phases { manifesto_id: 3 },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::ManifestoNotFound { manifesto_id: 3 });
+}
+
+#[test]
+fn migrating_from_v1_0_0() {
+    let mut deps = OwnedDeps {
+        storage: MockStorage::default(),
+        api: MockApi::default(),
+        querier: LegacyMedals,
+    };
+
+    // v1.0.0 state whose Martian dates, sent by signees, are in a different order than their
+    // addresses
+    load_fixture(deps.as_mut(), include_str!("testdata/v1_0_0_state.json"));
+    let migrate_msg = |content_hash: &str| MigrateMsg {
+        title: Some("Mars Manifesto".to_string()),
+        content_hash: Some(content_hash.to_string()),
+        uri: None,
+    };
+
+    let err = migrate(deps.as_mut(), mock_env(), migrate_msg("abcd")).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidHash {
+            hash: "abcd".to_string()
+        }
+    );
+    let err = migrate(
+        deps.as_mut(),
+        mock_env(),
+        MigrateMsg {
+            title: None,
+            content_hash: None,
+            uri: None,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::MissingLegacyManifesto {});
+    let res = migrate(deps.as_mut(), mock_env(), migrate_msg(CONTENT_HASH)).unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "migrate"),
+            attr("from_version", "1.0.0"),
+            attr("to_version", CONTRACT_VERSION),
+        ]
+    );
+    assert_eq!(
+        get_contract_version(&deps.storage).unwrap(),
+        ContractVersion {
+            contract: CONTRACT_NAME.to_string(),
+            version: CONTRACT_VERSION.to_string(),
+        }
+    );
+
+    let res = query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::Manifesto {
+            manifesto_id: MANIFESTO_ID,
+        },
+    )
+    .unwrap();
+    let manifesto: ManifestoResponse = from_binary(&res).unwrap();
+    assert_eq!(
+        manifesto,
+        ManifestoResponse {
+            id: MANIFESTO_ID,
+            title: "Mars Manifesto".to_string(),
+            version: 1,
+            content_hash: CONTENT_HASH.to_string(),
+            uri: None,
+            max_signees_allowed: MAX_SIGNEES,
+            medal_addr: Addr::unchecked(MEDAL),
+            medal_redeem_addr: Addr::unchecked(MEDAL_REDEEM),
+            signee_count: 3,
+        }
+    );
+    let res = query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap();
+    let config: ConfigResponse = from_binary(&res).unwrap();
+    assert_eq!(
+        config,
+        ConfigResponse {
+            admin: ADMIN.to_string(),
+            time_tolerance: DEFAULT_TIME_TOLERANCE,
+            manifestos_count: 1,
+            signing_fee: None,
+            import_finalized: false,
+        }
+    );
+
+    // Signatures are moved in batches, given the MEDAL v1.0.0 minted to each signee, before
+    // which manifesto 1 can't be signed
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("signee", &[]),
+        sign_msg(MARTIAN_DATE, MARTIAN_TIME),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::LegacySignaturesPending {});
+    let migrate_signatures = |entries: &[(&str, &str)]| ExecuteMsg::MigrateLegacySignatures {
+        entries: entries
+            .iter()
+            .map(|(signee, token_id)| LegacyMedal {
+                signee: signee.to_string(),
+                token_id: token_id.to_string(),
+            })
+            .collect(),
+    };
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("signee", &[]),
+        migrate_signatures(&[("cydonia", "1")]),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        migrate_signatures(&[("signee", "1")]),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::LegacySignatureNotFound {
+            signee: "signee".to_string()
+        }
+    );
+    for token_id in &["0", "4", "one"] {
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ADMIN, &[]),
+            migrate_signatures(&[("cydonia", token_id)]),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidLegacyTokenId {
+                token_id: token_id.to_string()
+            }
+        );
+    }
+    // "cydonia" may since hold the MEDAL of "borealis", which is not the one minted for them
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        migrate_signatures(&[("cydonia", "2")]),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::LegacyMedalMismatch {
+            signee: "cydonia".to_string(),
+            token_id: "2".to_string(),
+        }
+    );
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        migrate_signatures(&[("cydonia", "1"), ("borealis", "2")]),
+    )
+    .unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "migrate_legacy_signatures"),
+            attr("migrated", "2"),
+            attr("done", "false"),
+        ]
+    );
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("signee", &[]),
+        sign_msg(MARTIAN_DATE, MARTIAN_TIME),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::LegacySignaturesPending {});
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        migrate_signatures(&[("cydonia", "1")]),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::LegacySignatureNotFound {
+            signee: "cydonia".to_string()
+        }
+    );
+    // The MEDAL of "aurora" was redeemed, so can't be checked
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        migrate_signatures(&[("aurora", "3")]),
+    )
+    .unwrap();
+    assert_eq!(attr("migrated", "1"), res.attributes[1]);
+    assert_eq!(attr("done", "true"), res.attributes[2]);
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        migrate_signatures(&[]),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::LegacySignaturesMigrated {});
+
+    // Ordinals are the token ids of the MEDALs, i.e. the original signing order
+    let signature = query_signature(deps.as_ref(), "borealis");
+    assert_eq!(2, signature.ordinal);
+    assert_eq!(Some("2".to_string()), signature.medal_token_id);
+    assert!(signature.signed_at <= mock_env().block.time);
+    let signature = query_signature(deps.as_ref(), "cydonia");
+    assert_eq!(1, signature.ordinal);
+    assert_eq!(Some("1".to_string()), signature.medal_token_id);
+    let res = query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::AllSignatures {
+            manifesto_id: MANIFESTO_ID,
+            start_after: None,
+            limit: None,
+            order: None,
+        },
+    )
+    .unwrap();
+    let res: SignaturesResponse = from_binary(&res).unwrap();
+    assert_eq!(
+        vec!["cydonia", "borealis", "aurora"],
+        res.signatures
+            .iter()
+            .map(|s| s.signee.as_str())
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(Some("3".to_string()), res.signatures[2].medal_token_id);
+    assert_eq!(Timestamp::from_nanos(0), res.signatures[2].signed_at);
+    assert!(v1_0_0::SIGNATURES
+        .may_load(&deps.storage, b"aurora")
+        .unwrap()
+        .is_none());
+
+    // Signing continues after the migrated signatures, with the migrated metadata
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("signee", &[]),
+        sign_msg(MARTIAN_DATE, MARTIAN_TIME),
+    )
+    .unwrap();
+    match &res.messages[0].msg {
+        CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) => match from_binary(msg).unwrap() {
+            MedalExecuteMsg::Mint(mint_msg) => assert_eq!("MEDAL #4", mint_msg.name),
+            m => panic!("unexpected medal msg: {:?}", m),
+        },
+        m => panic!("unexpected msg: {:?}", m),
+    }
+    assert_eq!(4, query_signature(deps.as_ref(), "signee").ordinal);
+}
+
+#[test]
+fn amending_manifesto() {
+    let mut deps = mock_dependencies(&[]);
+    setup_contract(deps.as_mut());
+
+    let amended_hash = hex::encode(sha256(b"Amended Mars Manifesto"));
+    let sign = |content_hash: &str| ExecuteMsg::SignManifesto {
+        manifesto_id: MANIFESTO_ID,
+        content_hash: content_hash.to_string(),
+        martian_date: None,
+        martian_time: None,
+        proof: None,
+    };
+
+    // Signee must echo the hash of the current version
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("signee", &[]),
+        sign(&amended_hash),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::ContentHashMismatch {
+            manifesto_id: 1,
+            version: 1
+        }
+    );
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("signee", &[]),
+        sign(CONTENT_HASH),
+    )
+    .unwrap();
+
+    // Only admin can amend the manifesto
+    let msg = ExecuteMsg::AmendManifesto {
+        manifesto_id: MANIFESTO_ID,
+        content_hash: amended_hash.clone(),
+        uri: Some("ipfs://amended_manifesto".to_string()),
+    };
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("signee", &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(60);
+    execute(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]), msg).unwrap();
+
+    // Signatures of the previous version are rejected
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("other", &[]),
+        sign(CONTENT_HASH),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::ContentHashMismatch {
+            manifesto_id: 1,
+            version: 2
+        }
+    );
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("other", &[]),
+        sign(&amended_hash),
+    )
+    .unwrap();
+    assert_eq!(1, res.messages.len());
+    assert_eq!(2, query_signature(deps.as_ref(), "other").version);
+
+    // Signees of the previous version re-sign without minting another MEDAL
+    assert_eq!(1, query_signature(deps.as_ref(), "signee").version);
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("signee", &[]),
+        sign(&amended_hash),
+    )
+    .unwrap();
+    assert!(res.messages.is_empty());
+    let signature = query_signature(deps.as_ref(), "signee");
+    assert_eq!((1, 2), (signature.ordinal, signature.version));
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("signee", &[]),
+        sign(&amended_hash),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::AlreadySigned {});
+    let res = query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::State {
+            manifesto_id: MANIFESTO_ID,
+        },
+    )
+    .unwrap();
+    let state: StateResponse = from_binary(&res).unwrap();
+    assert_eq!(2, state.signee_count);
+
+    // Current and historical versions
+    let res = query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::ManifestoVersion {
+            manifesto_id: MANIFESTO_ID,
+            version: None,
+        },
+    )
+    .unwrap();
+    let current: ManifestoVersionResponse = from_binary(&res).unwrap();
+    assert_eq!(
+        current,
+        ManifestoVersionResponse {
+            manifesto_id: MANIFESTO_ID,
+            version: 2,
+            content_hash: amended_hash,
+            uri: Some("ipfs://amended_manifesto".to_string()),
+            published_at: env.block.time,
+        }
+    );
+    let res = query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::ManifestoVersions {
+            manifesto_id: MANIFESTO_ID,
+            start_after: None,
+            limit: None,
+        },
+    )
+    .unwrap();
+    let res: ManifestoVersionsResponse = from_binary(&res).unwrap();
+    assert_eq!(
+        vec![(1, CONTENT_HASH.to_string()), (2, current.content_hash)],
+        res.versions
+            .into_iter()
+            .map(|v| (v.version, v.content_hash))
+            .collect::<Vec<_>>()
+    );
+    let err = query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::ManifestoVersion {
+            manifesto_id: MANIFESTO_ID,
+            version: Some(3),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::VersionNotFound {
+            manifesto_id: 1,
+            version: 3
+        }
+    );
+}
+
+#[test]
+fn withdrawing_signature() {
+    let mut deps = mock_dependencies(&[]);
+    setup_contract(deps.as_mut());
+
+    let withdraw = ExecuteMsg::WithdrawSignature {
+        manifesto_id: MANIFESTO_ID,
+    };
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("signee", &[]),
+        withdraw.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::NotSigned {});
+
+    for (i, signee) in ["signee", "other"].iter().enumerate() {
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(signee, &[]),
+            sign_msg(MARTIAN_DATE, MARTIAN_TIME),
+        )
+        .unwrap();
+        reply(deps.as_mut(), mock_env(), mint_reply(&(i + 1).to_string())).unwrap();
+    }
+
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(60);
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("signee", &[]),
+        withdraw.clone(),
+    )
+    .unwrap();
+
+    // MEDAL contract burns the token if the signee still holds it
+    assert_eq!(
+        res.messages[0].msg,
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: MEDAL.to_string(),
+            msg: to_binary(&MedalExecuteMsg::BurnMedal {
+                token_id: "1".to_string(),
+                owner: "signee".to_string(),
+            })
+            .unwrap(),
+            funds: vec![],
+        })
+    );
+
+    let signature = query_signature(deps.as_ref(), "signee");
+    assert_eq!(Some(env.block.time), signature.withdrawn_at);
+    let res = query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::State {
+            manifesto_id: MANIFESTO_ID,
+        },
+    )
+    .unwrap();
+    let state: StateResponse = from_binary(&res).unwrap();
+    assert_eq!(
+        state,
+        StateResponse {
+            signee_count: 2,
+            withdrawn_count: 1,
+            active_signee_count: 1,
+        }
+    );
+
+    // Withdrawn signatures are no longer counted on their sol and month
+    let histogram = |deps: Deps| -> SignatureHistogramResponse {
+        let res = query(
+            deps,
+            mock_env(),
+            QueryMsg::SignatureHistogram {
+                manifesto_id: MANIFESTO_ID,
+                from: MARTIAN_DATE.to_string(),
+                to: MARTIAN_DATE.to_string(),
+            },
+        )
+        .unwrap();
+        from_binary(&res).unwrap()
+    };
+    assert_eq!(
+        histogram(deps.as_ref()),
+        SignatureHistogramResponse {
+            sols: vec![SolCount {
+                martian_date: MARTIAN_DATE.to_string(),
+                count: 1,
+            }],
+            months: vec![MonthCount {
+                year: 218,
+                month: "Mina".to_string(),
+                count: 1,
+            }],
+        }
+    );
+
+    // Cannot withdraw twice, nor sign again
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("signee", &[]),
+        withdraw,
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::AlreadyWithdrawn {});
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("signee", &[]),
+        sign_msg(MARTIAN_DATE, MARTIAN_TIME),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::SignatureWithdrawn {});
+
+    // Ordinals of later signees are unchanged
+    assert_eq!(2, query_signature(deps.as_ref(), "other").ordinal);
+
+    // Counts dropping to zero are removed
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("other", &[]),
+        ExecuteMsg::WithdrawSignature {
+            manifesto_id: MANIFESTO_ID,
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        histogram(deps.as_ref()),
+        SignatureHistogramResponse {
+            sols: vec![],
+            months: vec![],
+        }
+    );
+}
+
+fn bech32_address(data: &[u8]) -> String {
+    bech32::encode("terra", data.to_base32(), Variant::Bech32).unwrap()
+}
+
+#[test]
+fn relaying_signatures() {
+    let mut deps = mock_dependencies(&[]);
+    setup_contract(deps.as_mut());
+
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked(bech32_address(&[7u8; 20]));
+
+    let signing_key = SigningKey::from_bytes(&[42u8; 32]).unwrap();
+    let signee_pubkey = VerifyingKey::from(&signing_key).to_bytes().to_vec();
+    let pubkey_hash = ripemd160::Ripemd160::digest(&sha256(&signee_pubkey));
+    let signee = bech32_address(&pubkey_hash);
+
+    let payload = |nonce: u64| SignManifestoPayload {
+        chain_id: env.block.chain_id.clone(),
+        contract_addr: env.contract.address.to_string(),
+        manifesto_id: MANIFESTO_ID,
+        content_hash: CONTENT_HASH.to_string(),
+        martian_date: None,
+        martian_time: None,
+        nonce,
+    };
+    let sign = |payload: &SignManifestoPayload| -> Vec<u8> {
+        let signature: Signature = signing_key.sign(&cosmwasm_std::to_vec(payload).unwrap());
+        signature.as_ref().to_vec()
+    };
+    let relay = |signature: Vec<u8>, nonce: u64| ExecuteMsg::SignManifestoFor {
+        manifesto_id: MANIFESTO_ID,
+        content_hash: CONTENT_HASH.to_string(),
+        signee_pubkey: signee_pubkey.clone().into(),
+        signature: signature.into(),
+        martian_date: None,
+        martian_time: None,
+        nonce,
+        proof: None,
+    };
+
+    // Public key must be a compressed secp256k1 key, and the signee address is derived with
+    // the bech32 prefix of the contract address
+    let mut invalid_pubkey = relay(sign(&payload(0)), 0);
+    if let ExecuteMsg::SignManifestoFor { signee_pubkey, .. } = &mut invalid_pubkey {
+        *signee_pubkey = vec![2u8; 32].into();
+    }
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("relayer", &[]),
+        invalid_pubkey,
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::InvalidPubkey {});
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("relayer", &[]),
+        relay(sign(&payload(0)), 0),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidContractAddress {
+            address: mock_env().contract.address.to_string()
+        }
+    );
+
+    // Payloads of another chain are rejected
+    let mut other_chain = payload(0);
+    other_chain.chain_id = "other-chain".to_string();
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("relayer", &[]),
+        relay(sign(&other_chain), 0),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::InvalidSignature {});
+
+    // Nonce must be the next one of the signee
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("relayer", &[]),
+        relay(sign(&payload(1)), 1),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidNonce {
+            nonce: 1,
+            expected: 0
+        }
+    );
+
+    // Relayer pays the fee, signature and MEDAL go to the signee
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("relayer", &[]),
+        relay(sign(&payload(0)), 0),
+    )
+    .unwrap();
+    match &res.messages[0].msg {
+        CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) => match from_binary(msg).unwrap() {
+            MedalExecuteMsg::Mint(mint_msg) => assert_eq!(signee, mint_msg.owner),
+            m => panic!("unexpected medal msg: {:?}", m),
+        },
+        m => panic!("unexpected msg: {:?}", m),
+    }
+    assert!(res
+        .attributes
+        .iter()
+        .any(|a| a.key == "relayer" && a.value == "relayer"));
+    assert_eq!(1, query_signature(deps.as_ref(), &signee).ordinal);
+
+    let res = query(
+        deps.as_ref(),
+        env.clone(),
+        QueryMsg::Nonce {
+            address: signee.clone(),
+        },
+    )
+    .unwrap();
+    let res: NonceResponse = from_binary(&res).unwrap();
+    assert_eq!(1, res.nonce);
+
+    // Replaying the same payload fails
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("relayer", &[]),
+        relay(sign(&payload(0)), 0),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidNonce {
+            nonce: 0,
+            expected: 1,
+        }
+    );
+}
+
+#[test]
+fn transferring_admin() {
+    let mut deps = mock_dependencies(&[]);
+    setup_contract(deps.as_mut());
+
+    let height = mock_env().block.height;
+    let query_proposal = |deps: Deps| -> AdminProposalResponse {
+        from_binary(&query(deps, mock_env(), QueryMsg::AdminProposal {}).unwrap()).unwrap()
+    };
+    let query_admin = |deps: Deps| -> String {
+        let res = query(deps, mock_env(), QueryMsg::Config {}).unwrap();
+        from_binary::<ConfigResponse>(&res).unwrap().admin
+    };
+
+    // Only admin can propose, with an expiration in the future
+    let propose = ExecuteMsg::ProposeNewAdmin {
+        new_admin: "new_admin".to_string(),
+        expires: Expiration::AtHeight(height + 10),
+    };
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("new_admin", &[]),
+        propose.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        ExecuteMsg::ProposeNewAdmin {
+            new_admin: "new_admin".to_string(),
+            expires: Expiration::AtHeight(height),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::AdminProposalAlreadyExpired {});
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("new_admin", &[]),
+        ExecuteMsg::ClaimAdmin {},
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::NoAdminProposal {});
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        propose.clone(),
+    )
+    .unwrap();
+    assert_eq!(
+        query_proposal(deps.as_ref()),
+        AdminProposalResponse {
+            new_admin: Some("new_admin".to_string()),
+            expires: Some(Expiration::AtHeight(height + 10)),
+        }
+    );
+
+    // Proposal can be dropped by the admin
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("new_admin", &[]),
+        ExecuteMsg::DropAdminProposal {},
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        ExecuteMsg::DropAdminProposal {},
+    )
+    .unwrap();
+    assert_eq!(None, query_proposal(deps.as_ref()).new_admin);
+
+    execute(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]), propose).unwrap();
+
+    // Only the proposed admin can claim, before expiry
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("other", &[]),
+        ExecuteMsg::ClaimAdmin {},
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    let mut env = mock_env();
+    env.block.height = height + 10;
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("new_admin", &[]),
+        ExecuteMsg::ClaimAdmin {},
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::AdminProposalExpired {});
+    assert_eq!(ADMIN, query_admin(deps.as_ref()));
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("new_admin", &[]),
+        ExecuteMsg::ClaimAdmin {},
+    )
+    .unwrap();
+    assert_eq!("new_admin", query_admin(deps.as_ref()));
+    assert_eq!(None, query_proposal(deps.as_ref()).new_admin);
+
+    // Previous admin lost its rights
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        ExecuteMsg::UpdateTimeTolerance { time_tolerance: 1 },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+}
+
+#[test]
+fn migrating_versions() {
+    let mut deps = mock_dependencies(&[]);
+    setup_contract(deps.as_mut());
+    let msg = MigrateMsg {
+        title: None,
+        content_hash: None,
+        uri: None,
+    };
+
+    // Migrating from the current version leaves state untouched
+    assert_eq!(
+        get_contract_version(&deps.storage).unwrap().version,
+        CONTRACT_VERSION
+    );
+    migrate(deps.as_mut(), mock_env(), msg.clone()).unwrap();
+    let res = query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap();
+    assert_eq!(
+        1,
+        from_binary::<ConfigResponse>(&res)
+            .unwrap()
+            .manifestos_count
+    );
+
+    // Refuses downgrades and other contracts
+    set_contract_version(&mut deps.storage, CONTRACT_NAME, "99.0.0").unwrap();
+    let err = migrate(deps.as_mut(), mock_env(), msg.clone()).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::CannotDowngrade {
+            previous_version: "99.0.0".to_string(),
+            new_version: CONTRACT_VERSION.to_string(),
+        }
+    );
+    set_contract_version(&mut deps.storage, "crates.io:cw721-base", CONTRACT_VERSION).unwrap();
+    let err = migrate(deps.as_mut(), mock_env(), msg).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::ContractNameMismatch {
+            contract: "crates.io:cw721-base".to_string()
+        }
+    );
+}
+
+/// Raw storage entry of a state fixture, with a hex encoded key and a JSON value
+#[derive(serde::Deserialize)]
+struct StorageEntry {
+    key: String,
+    value: serde_json::Value,
+}
+
+fn load_fixture(deps: DepsMut, fixture: &str) {
+    let entries: Vec<StorageEntry> = serde_json::from_str(fixture).unwrap();
+    for entry in entries {
+        deps.storage.set(
+            &hex::decode(entry.key).unwrap(),
+            &serde_json::to_vec(&entry.value).unwrap(),
+        );
+    }
+}
+
+#[test]
+fn confirming_medal_mint() {
+    let mut deps = mock_dependencies(&[]);
+    setup_contract(deps.as_mut());
+
+    let retry = ExecuteMsg::RetryMedalMint {
+        manifesto_id: MANIFESTO_ID,
+    };
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("signee", &[]),
+        retry.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::NotSigned {});
+
+    // MEDAL is minted through a SubMsg, whose reply confirms the token id
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("signee", &[]),
+        sign_msg(MARTIAN_DATE, MARTIAN_TIME),
+    )
+    .unwrap();
+    assert_eq!(
+        (1, ReplyOn::Always),
+        (res.messages[0].id, res.messages[0].reply_on.clone())
+    );
+    let res = reply(deps.as_mut(), mock_env(), mint_reply("1")).unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("manifesto_id", "1"),
+            attr("signee", "signee"),
+            attr("action", "confirm_medal_mint"),
+            attr("medal_token_id", "1"),
+        ]
+    );
+    assert_eq!(
+        Some("1".to_string()),
+        query_signature(deps.as_ref(), "signee").medal_token_id
+    );
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("signee", &[]),
+        retry.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::MedalAlreadyMinted {
+            token_id: "1".to_string()
+        }
+    );
+
+    // A failed mint leaves the MEDAL pending without failing the signature
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("other", &[]),
+        sign_msg(MARTIAN_DATE, MARTIAN_TIME),
+    )
+    .unwrap();
+    let failure = Reply {
+        id: 1,
+        result: ContractResult::Err("Unauthorized".to_string()),
+    };
+    let res = reply(deps.as_mut(), mock_env(), failure).unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("manifesto_id", "1"),
+            attr("signee", "other"),
+            attr("action", "medal_mint_failed"),
+            attr("error", "Unauthorized"),
+        ]
+    );
+    let signature = query_signature(deps.as_ref(), "other");
+    assert_eq!((2, None), (signature.ordinal, signature.medal_token_id));
+
+    // Signee can retry minting their MEDAL
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("other", &[]),
+        retry.clone(),
+    )
+    .unwrap();
+    assert_eq!(1, res.messages[0].id);
+    match &res.messages[0].msg {
+        CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) => match from_binary(msg).unwrap() {
+            MedalExecuteMsg::Mint(mint_msg) => {
+                assert_eq!("2", mint_msg.token_id);
+                assert_eq!("other", mint_msg.owner);
+            }
+            m => panic!("unexpected medal msg: {:?}", m),
+        },
+        m => panic!("unexpected msg: {:?}", m),
+    }
+
+    // A successful mint which reports no token id leaves the MEDAL pending
+    let mut unreported = mint_reply("2");
+    if let ContractResult::Ok(execution_response) = &mut unreported.result {
+        execution_response.events[0] = Event::new("wasm").add_attribute("action", "mint");
+        execution_response.data = None;
+    }
+    let res = reply(deps.as_mut(), mock_env(), unreported).unwrap();
+    assert_eq!(attr("action", "medal_mint_unconfirmed"), res.attributes[2]);
+    assert_eq!(None, query_signature(deps.as_ref(), "other").medal_token_id);
+
+    // Without response data, the token id is read from the `token_id` attribute of the events
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("other", &[]),
+        retry.clone(),
+    )
+    .unwrap();
+    let mut no_data = mint_reply("2");
+    if let ContractResult::Ok(execution_response) = &mut no_data.result {
+        execution_response.events[0] = Event::new("wasm").add_attribute("token_id", "2");
+        execution_response.data = None;
+    }
+    let res = reply(deps.as_mut(), mock_env(), no_data).unwrap();
+    assert_eq!(attr("action", "confirm_medal_mint"), res.attributes[2]);
+    assert_eq!(
+        Some("2".to_string()),
+        query_signature(deps.as_ref(), "other").medal_token_id
+    );
+
+    // Withdrawing a signature with a pending MEDAL burns nothing, and it can't be retried
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("earth", &[]),
+        sign_msg(MARTIAN_DATE, MARTIAN_TIME),
+    )
+    .unwrap();
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("earth", &[]),
+        ExecuteMsg::WithdrawSignature {
+            manifesto_id: MANIFESTO_ID,
+        },
+    )
+    .unwrap();
+    assert!(res.messages.is_empty());
+    let err = execute(deps.as_mut(), mock_env(), mock_info("earth", &[]), retry).unwrap_err();
+    assert_eq!(err, ContractError::SignatureWithdrawn {});
+
+    let err = reply(
+        deps.as_mut(),
+        mock_env(),
+        Reply {
+            id: 7,
+            result: ContractResult::Err("".to_string()),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::UnknownReplyId { id: 7 });
+}
+
+#[test]
+fn charging_signing_fee() {
+    let mut deps = mock_dependencies_with_tax(1_000_000);
+    setup_contract(deps.as_mut());
+
+    // Only admin can set the fee, with positive amounts in unique denoms
+    let update_fee = |amounts: Vec<Coin>| ExecuteMsg::UpdateSigningFee {
+        treasury: "treasury".to_string(),
+        amounts,
+    };
+    let fee = vec![coin(1_000_000, "uusd"), coin(10_000, "uluna")];
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("signee", &[]),
+        update_fee(fee.clone()),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        update_fee(vec![coin(0, "uusd")]),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::ZeroFeeAmount {
+            denom: "uusd".to_string()
+        }
+    );
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        update_fee(vec![coin(1, "uusd"), coin(2, "uusd")]),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::DuplicateFeeDenom {
+            denom: "uusd".to_string()
+        }
+    );
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        update_fee(fee.clone()),
+    )
+    .unwrap();
+    let res = query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap();
+    assert_eq!(
+        Some(SigningFee {
+            treasury: Addr::unchecked("treasury"),
+            amounts: fee,
+        }),
+        from_binary::<ConfigResponse>(&res).unwrap().signing_fee
+    );
+
+    // Fee must be paid in full, in a single accepted denom
+    let sign = sign_msg(MARTIAN_DATE, MARTIAN_TIME);
+    let cases = vec![
+        (vec![], ContractError::MissingFee {}),
+        (
+            vec![coin(1_000_000, "uusd"), coin(10_000, "uluna")],
+            ContractError::MultipleFeeDenoms {},
+        ),
+        (
+            vec![coin(1_000_000, "ukrw")],
+            ContractError::UnacceptedDenom {
+                denom: "ukrw".to_string(),
+            },
+        ),
+        (
+            vec![coin(999_999, "uusd")],
+            ContractError::InsufficientFee {
+                denom: "uusd".to_string(),
+                required: 1_000_000u128.into(),
+                sent: 999_999u128.into(),
+            },
+        ),
+    ];
+    for (funds, expected) in cases {
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("signee", &funds),
+            sign.clone(),
+        )
+        .unwrap_err();
+        assert_eq!(err, expected);
+    }
+
+    // Treasury receives the fee net of the stability tax: 1_000_000 - 1_000_000 / 1.001
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("signee", &[coin(1_000_000, "uusd")]),
+        sign.clone(),
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages[1].msg,
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: "treasury".to_string(),
+            amount: vec![coin(999_000, "uusd")],
+        })
+    );
+    assert_eq!(
+        Some(&attr("signing_fee", "1000000uusd")),
+        res.attributes.last()
+    );
+
+    // LUNA is exempted from the stability tax
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("other", &[coin(10_000, "uluna")]),
+        sign.clone(),
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages[1].msg,
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: "treasury".to_string(),
+            amount: vec![coin(10_000, "uluna")],
+        })
+    );
+
+    // Signing is free once the fee is removed
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        update_fee(vec![]),
+    )
+    .unwrap();
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("earth", &[coin(1_000_000, "uusd")]),
+        sign.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::FundsNotRequired {});
+    let res = execute(deps.as_mut(), mock_env(), mock_info("earth", &[]), sign).unwrap();
+    assert_eq!(1, res.messages.len());
+}
+
+#[test]
+fn capping_stability_tax() {
+    let deps = mock_dependencies_with_tax(500);
+    let querier = QuerierWrapper::new(&deps.querier);
+
+    let net = deduct_tax(&querier, coin(1_000_000, "uusd")).unwrap();
+    assert_eq!(coin(999_500, "uusd"), net);
+}
+
+#[test]
+fn pausing_signing() {
+    let mut deps = mock_dependencies(&[]);
+    setup_contract(deps.as_mut());
+    let env = mock_env();
+
+    // only the admin can set the guardian
+    let guardian_msg = ExecuteMsg::UpdateGuardian {
+        guardian: Some("guardian".to_string()),
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("guardian", &[]),
+        guardian_msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Pause(PauseError::Unauthorized {}));
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(ADMIN, &[]),
+        guardian_msg,
+    )
+    .unwrap();
+
+    // only the guardian or the admin can pause
+    let pause_msg = ExecuteMsg::Pause {
+        flags: vec![PauseFlag::Signing],
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("mallory", &[]),
+        pause_msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Pause(PauseError::Unauthorized {}));
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("guardian", &[]),
+        pause_msg,
+    )
+    .unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![attr("action", "pause"), attr("flags", "signing")]
+    );
+
+    let res: PausedResponse =
+        from_binary(&query(deps.as_ref(), env.clone(), QueryMsg::Paused {}).unwrap()).unwrap();
+    assert_eq!(Some(Addr::unchecked("guardian")), res.guardian);
+    assert!(res.paused.signing);
+    assert!(!res.paused.minting);
+
+    let sign_msg = sign_msg(MARTIAN_DATE, MARTIAN_TIME);
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("aurora", &[]),
+        sign_msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Pause(PauseError::Paused {
+            flag: PauseFlag::Signing
+        })
+    );
+
+    // the guardian cannot unpause
+    let unpause_msg = ExecuteMsg::Unpause {
+        flags: vec![PauseFlag::Signing],
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("guardian", &[]),
+        unpause_msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Pause(PauseError::Unauthorized {}));
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(ADMIN, &[]),
+        unpause_msg,
+    )
+    .unwrap();
+
+    execute(deps.as_mut(), env, mock_info("aurora", &[]), sign_msg).unwrap();
+}
+
+#[test]
+fn templating_medal_traits() {
+    let mut deps = mock_dependencies(&[]);
+    setup_contract(deps.as_mut());
+
+    let query_template = |deps: Deps| -> MedalTemplate {
+        let msg = QueryMsg::MedalTemplate {
+            manifesto_id: MANIFESTO_ID,
+        };
+        from_binary(&query(deps, mock_env(), msg).unwrap()).unwrap()
+    };
+    assert_eq!(MedalTemplate::default(), query_template(deps.as_ref()));
+
+    let trait_template = |trait_type: &str, display_type, value| TraitTemplate {
+        trait_type: trait_type.to_string(),
+        display_type,
+        value,
+    };
+    let template = MedalTemplate {
+        traits: vec![
+            trait_template("edition", None, TraitValue::Static("Genesis".to_string())),
+            trait_template(
+                "ordinal",
+                Some(DisplayType::Number),
+                TraitValue::Placeholder(TraitPlaceholder::Ordinal),
+            ),
+            trait_template(
+                "block_height",
+                Some(DisplayType::Number),
+                TraitValue::Placeholder(TraitPlaceholder::BlockHeight),
+            ),
+            trait_template(
+                "earth_date",
+                Some(DisplayType::Date),
+                TraitValue::Placeholder(TraitPlaceholder::EarthDate),
+            ),
+            trait_template(
+                "darian_month",
+                None,
+                TraitValue::Placeholder(TraitPlaceholder::DarianMonth),
+            ),
+            trait_template(
+                "signing_phase",
+                None,
+                TraitValue::Placeholder(TraitPlaceholder::SigningPhase),
+            ),
+            trait_template(
+                "martian_date",
+                None,
+                TraitValue::Placeholder(TraitPlaceholder::MartianDate),
+            ),
+            trait_template(
+                "martian_time",
+                None,
+                TraitValue::Placeholder(TraitPlaceholder::MartianTime),
+            ),
+        ],
+        background_color: Some("c1440e".to_string()),
+        animation_url: Some("ipfs://medal_animation".to_string()),
+    };
+
+    // only the admin can update the template
+    let msg = ExecuteMsg::UpdateMedalTemplate {
+        manifesto_id: MANIFESTO_ID,
+        template: Some(template.clone()),
+    };
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("signee", &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    // the background color must be a hex color
+    let invalid_msg = ExecuteMsg::UpdateMedalTemplate {
+        manifesto_id: MANIFESTO_ID,
+        template: Some(MedalTemplate {
+            background_color: Some("#c1440e".to_string()),
+            ..template.clone()
+        }),
+    };
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        invalid_msg,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidBackgroundColor {
+            color: "#c1440e".to_string()
+        }
+    );
+
+    // the traits SVG images are rendered from must be kept
+    let mut without_time = template.clone();
+    without_time.traits.pop();
+    let mut renamed_date = template.clone();
+    renamed_date.traits[6].trait_type = "date".to_string();
+    for (invalid_template, trait_type) in [
+        (without_time, "martian_time"),
+        (renamed_date, "martian_date"),
+    ] {
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ADMIN, &[]),
+            ExecuteMsg::UpdateMedalTemplate {
+                manifesto_id: MANIFESTO_ID,
+                template: Some(invalid_template),
+            },
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::MissingSvgTrait {
+                trait_type: trait_type.to_string()
+            }
+        );
+    }
+
+    execute(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]), msg).unwrap();
+    assert_eq!(template, query_template(deps.as_ref()));
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("signee", &[]),
+        sign_msg(MARTIAN_DATE, MARTIAN_TIME),
+    )
+    .unwrap();
+
+    let env = mock_env();
+    let expected_traits = vec![
+        ("edition", None, "Genesis".to_string()),
+        ("ordinal", Some("number"), "1".to_string()),
+        ("block_height", Some("number"), env.block.height.to_string()),
+        (
+            "earth_date",
+            Some("date"),
+            env.block.time.seconds().to_string(),
+        ),
+        ("darian_month", None, "Mina".to_string()),
+        ("signing_phase", None, "0".to_string()),
+        ("martian_date", None, MARTIAN_DATE.to_string()),
+        ("martian_time", None, MARTIAN_TIME.to_string()),
+    ]
+    .into_iter()
+    .map(|(trait_type, display_type, value)| Trait {
+        display_type: display_type.map(String::from),
+        trait_type: trait_type.to_string(),
+        value,
+    })
+    .collect::<Vec<_>>();
+    match &res.messages[0].msg {
+        CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) => match from_binary(msg).unwrap() {
+            MedalExecuteMsg::Mint(mint_msg) => {
+                assert_eq!(Some(expected_traits), mint_msg.extension.attributes);
+                assert_eq!(
+                    Some("c1440e".to_string()),
+                    mint_msg.extension.background_color
+                );
+                assert_eq!(
+                    Some("ipfs://medal_animation".to_string()),
+                    mint_msg.extension.animation_url
+                );
+            }
+            m => panic!("unexpected medal msg: {:?}", m),
+        },
+        m => panic!("unexpected msg: {:?}", m),
+    }
+
+    // resetting the template
+    let msg = ExecuteMsg::UpdateMedalTemplate {
+        manifesto_id: MANIFESTO_ID,
+        template: None,
+    };
+    execute(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]), msg).unwrap();
+    assert_eq!(MedalTemplate::default(), query_template(deps.as_ref()));
+}
+
+#[test]
+fn minting_medal_tiers() {
+    let mut deps = mock_dependencies(&[]);
+    setup_contract(deps.as_mut());
+
+    let tier = |name: &str, first_ordinal, last_ordinal| MedalTier {
+        name: name.to_string(),
+        first_ordinal,
+        last_ordinal,
+        metadata: MedalMetaData {
+            name_prefix: name.to_string() + " MEDAL",
+            description: "A badge of honor for the ".to_string() + name,
+            image: "ipfs://".to_string() + name,
+            token_uri: "ipfs://".to_string() + name + "_token_uri",
+        },
+    };
+    let update_tiers = |deps: DepsMut, tiers: Vec<MedalTier>| {
+        let msg = ExecuteMsg::UpdateMedalTiers {
+            manifesto_id: MANIFESTO_ID,
+            tiers,
+        };
+        execute(deps, mock_env(), mock_info(ADMIN, &[]), msg)
+    };
+
+    // tiers need valid, disjoint ordinal ranges and unique names
+    let err = update_tiers(deps.as_mut(), vec![tier("Founding", 0, 2)]).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidTierRange {
+            tier: "Founding".to_string()
+        }
+    );
+    let err = update_tiers(deps.as_mut(), vec![tier("Founding", 3, 2)]).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidTierRange {
+            tier: "Founding".to_string()
+        }
+    );
+    let err = update_tiers(
+        deps.as_mut(),
+        vec![tier("Pioneer", 2, 3), tier("Founding", 1, 2)],
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::OverlappingTiers {
+            tier: "Pioneer".to_string()
+        }
+    );
+    let err = update_tiers(
+        deps.as_mut(),
+        vec![tier("Founding", 1, 2), tier("Founding", 3, 3)],
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::DuplicateTierName {
+            tier: "Founding".to_string()
+        }
+    );
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("signee", &[]),
+        ExecuteMsg::UpdateMedalTiers {
+            manifesto_id: MANIFESTO_ID,
+            tiers: vec![],
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    update_tiers(
+        deps.as_mut(),
+        vec![tier("Pioneer", 3, 3), tier("Founding", 1, 2)],
+    )
+    .unwrap();
+
+    for (i, signee) in ["aurora", "borealis", "cydonia", "deimos"]
+        .iter()
+        .enumerate()
+    {
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(signee, &[]),
+            sign_msg(MARTIAN_DATE, MARTIAN_TIME),
+        )
+        .unwrap();
+        let token_id = (i + 1).to_string();
+        let mint_msg = match &res.messages[0].msg {
+            CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) => match from_binary(msg).unwrap() {
+                MedalExecuteMsg::Mint(mint_msg) => mint_msg,
+                m => panic!("unexpected medal msg: {:?}", m),
+            },
+            m => panic!("unexpected msg: {:?}", m),
+        };
+        let traits = mint_msg.extension.attributes.unwrap();
+        match i {
+            0 | 1 => {
+                assert_eq!("Founding MEDAL #".to_string() + &token_id, mint_msg.name);
+                assert_eq!(
+                    Some("ipfs://Founding_token_uri".to_string()),
+                    mint_msg.image
+                );
+                assert_eq!("Founding", traits.last().unwrap().value);
+            }
+            2 => {
+                assert_eq!("Pioneer MEDAL #3", mint_msg.name);
+                assert_eq!("Pioneer", traits.last().unwrap().value);
+            }
+            _ => {
+                assert_eq!("MEDAL #4", mint_msg.name);
+                assert!(traits.iter().all(|t| t.trait_type != "tier"));
+            }
+        }
+
+        // the first MEDAL of the Pioneer tier fails to mint
+        let mint_reply = if i == 2 {
+            Reply {
+                id: 1,
+                result: ContractResult::Err("Minting is paused".to_string()),
+            }
+        } else {
+            mint_reply(&token_id)
+        };
+        reply(deps.as_mut(), mock_env(), mint_reply).unwrap();
+    }
+
+    let res: MedalTiersResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::MedalTiers {
+                manifesto_id: MANIFESTO_ID,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    let tiers = res
+        .tiers
+        .iter()
+        .map(|t| (t.tier.name.as_str(), t.tier.first_ordinal, t.minted_count))
+        .collect::<Vec<_>>();
+    assert_eq!(vec![("Founding", 1, 2), ("Pioneer", 3, 0)], tiers);
+}
+
+#[test]
+fn updating_medal_svg_template() {
+    let mut deps = mock_dependencies(&[]);
+    setup_contract(deps.as_mut());
+
+    let msg = ExecuteMsg::UpdateMedalSvgTemplate {
+        manifesto_id: MANIFESTO_ID,
+        template: Some("<svg>{{name}}</svg>".to_string()),
+    };
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("signee", &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    // the MEDAL contract stores the template, as the manifesto contract is its minter
+    let res = execute(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]), msg).unwrap();
+    match &res.messages[0].msg {
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr, msg, ..
+        }) => {
+            assert_eq!(MEDAL, contract_addr);
+            assert_eq!(
+                MedalExecuteMsg::UpdateSvgTemplate {
+                    template: Some("<svg>{{name}}</svg>".to_string())
+                },
+                from_binary(msg).unwrap()
+            );
+        }
+        m => panic!("unexpected msg: {:?}", m),
+    }
+}
+
+#[test]
+fn importing_signatures() {
+    let mut deps = mock_dependencies(&[]);
+    setup_contract(deps.as_mut());
+
+    let entry = |signee: &str, ordinal| ImportedSignature {
+        signee: signee.to_string(),
+        martian_date: "26 Taurus 219".to_string(),
+        martian_time: "09:05:33 MTC".to_string(),
+        ordinal,
+    };
+    let import_msg = |entries, mint_medals| ExecuteMsg::ImportSignatures {
+        manifesto_id: MANIFESTO_ID,
+        entries,
+        mint_medals,
+    };
+
+    // only the admin can import
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("aurora", &[]),
+        import_msg(vec![entry("aurora", 1)], false),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    // ordinals must continue the ones of the manifesto
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        import_msg(vec![entry("aurora", 2)], false),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::UnexpectedOrdinal {
+            ordinal: 2,
+            expected: 1
+        }
+    );
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        import_msg(vec![entry("aurora", 1), entry("borealis", 2)], false),
+    )
+    .unwrap();
+    assert!(res.messages.is_empty());
+    // one event per imported signature
+    assert_eq!(
+        res.events
+            .iter()
+            .map(|event| event.ty.as_str())
+            .collect::<Vec<_>>(),
+        vec!["manifesto_sign", "manifesto_sign"]
+    );
+    let signature = query_signature(deps.as_ref(), "borealis");
+    assert_eq!(2, signature.ordinal);
+    assert_eq!("26 Taurus 219", signature.martian_date);
+    assert_eq!(None, signature.medal_token_id);
+
+    // signatures can't be imported twice
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        import_msg(vec![entry("aurora", 3)], false),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::DuplicateSignature {
+            signee: "aurora".to_string()
+        }
+    );
+
+    // importing is paused with signing
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        ExecuteMsg::Pause {
+            flags: vec![PauseFlag::Signing],
+        },
+    )
+    .unwrap();
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        import_msg(vec![entry("cydonia", 3)], true),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Pause(PauseError::Paused {
+            flag: PauseFlag::Signing
+        })
+    );
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        ExecuteMsg::Unpause {
+            flags: vec![PauseFlag::Signing],
+        },
+    )
+    .unwrap();
+
+    // MEDALs can be minted in the same batch, pending until the replies of their mints
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        import_msg(vec![entry("cydonia", 3), entry("europa", 4)], true),
+    )
+    .unwrap();
+    assert_eq!(2, res.messages.len());
+    assert!(res
+        .messages
+        .iter()
+        .all(|message| message.reply_on == ReplyOn::Always));
+    assert_eq!(
+        None,
+        query_signature(deps.as_ref(), "cydonia").medal_token_id
+    );
+    assert_eq!(
+        None,
+        query_signature(deps.as_ref(), "europa").medal_token_id
+    );
+
+    // replies confirm the mints in the order they were sent, a failed one staying pending
+    let res = reply(deps.as_mut(), mock_env(), mint_reply("3")).unwrap();
+    assert_eq!(attr("signee", "cydonia"), res.attributes[1]);
+    assert_eq!(
+        Some("3".to_string()),
+        query_signature(deps.as_ref(), "cydonia").medal_token_id
+    );
+    let res = reply(
+        deps.as_mut(),
+        mock_env(),
+        Reply {
+            id: 1,
+            result: ContractResult::Err("Minting is paused".to_string()),
+        },
+    )
+    .unwrap();
+    assert_eq!(attr("signee", "europa"), res.attributes[1]);
+    assert_eq!(attr("action", "medal_mint_failed"), res.attributes[2]);
+    assert_eq!(
+        None,
+        query_signature(deps.as_ref(), "europa").medal_token_id
+    );
+
+    // signatures made afterwards get the following ordinals
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("deimos", &[]),
+        sign_msg(MARTIAN_DATE, MARTIAN_TIME),
+    )
+    .unwrap();
+    let res = reply(deps.as_mut(), mock_env(), mint_reply("5")).unwrap();
+    assert_eq!(attr("signee", "deimos"), res.attributes[1]);
+    assert_eq!(5, query_signature(deps.as_ref(), "deimos").ordinal);
+
+    let msg = QueryMsg::State {
+        manifesto_id: MANIFESTO_ID,
+    };
+    let state: StateResponse =
+        from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+    assert_eq!(5, state.signee_count);
+
+    // importing can be disabled for good
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        ExecuteMsg::FinalizeImport {},
+    )
+    .unwrap();
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        import_msg(vec![entry("phobos", 6)], false),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::ImportFinalized {});
+
+    let config: ConfigResponse =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap()).unwrap();
+    assert!(config.import_finalized);
+}
+
+#[test]
+fn querying_at_height() {
+    let mut deps = mock_dependencies(&[]);
+    setup_contract(deps.as_mut());
+    let registered_at = mock_env().block.height;
+
+    // Signs at the next two blocks, then the first signee withdraws
+    for (i, signee) in ["signee", "other"].iter().enumerate() {
+        let mut env = mock_env();
+        env.block.height = registered_at + i as u64 + 1;
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(signee, &[]),
+            sign_msg(MARTIAN_DATE, MARTIAN_TIME),
+        )
+        .unwrap();
+        reply(deps.as_mut(), env, mint_reply(&(i + 1).to_string())).unwrap();
+    }
+    let mut env = mock_env();
+    env.block.height = registered_at + 3;
+    execute(
+        deps.as_mut(),
+        env,
+        mock_info("signee", &[]),
+        ExecuteMsg::WithdrawSignature {
+            manifesto_id: MANIFESTO_ID,
+        },
+    )
+    .unwrap();
+
+    let state_at_height = |height: u64| -> StateResponse {
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::StateAtHeight {
+                manifesto_id: MANIFESTO_ID,
+                height,
+            },
+        )
+        .unwrap();
+        from_binary(&res).unwrap()
+    };
+    let signature_at_height = |signee: &str, height: u64| -> SignatureAtHeightResponse {
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::SignatureAtHeight {
+                manifesto_id: MANIFESTO_ID,
+                signee: signee.to_string(),
+                height,
+            },
+        )
+        .unwrap();
+        from_binary(&res).unwrap()
+    };
+    let state = |signee_count, withdrawn_count| StateResponse {
+        signee_count,
+        withdrawn_count,
+        active_signee_count: signee_count - withdrawn_count,
+    };
+
+    assert_eq!(state_at_height(registered_at - 1), state(0, 0));
+    assert_eq!(state_at_height(registered_at), state(0, 0));
+    assert_eq!(state_at_height(registered_at + 1), state(1, 0));
+    assert_eq!(state_at_height(registered_at + 2), state(2, 0));
+    assert_eq!(state_at_height(registered_at + 3), state(2, 1));
+    assert_eq!(state_at_height(registered_at + 100), state(2, 1));
+    assert_eq!(state_at_height(u64::MAX), state(2, 1));
+
+    let signed = |signee: &str, ordinal: Option<u64>| SignatureAtHeightResponse {
+        signee: Addr::unchecked(signee),
+        signed: ordinal.is_some(),
+        ordinal,
+    };
+    assert_eq!(
+        signature_at_height("signee", registered_at),
+        signed("signee", None)
+    );
+    assert_eq!(
+        signature_at_height("signee", registered_at + 1),
+        signed("signee", Some(1))
+    );
+    assert_eq!(
+        signature_at_height("signee", registered_at + 2),
+        signed("signee", Some(1))
+    );
+    assert_eq!(
+        signature_at_height("signee", registered_at + 3),
+        signed("signee", None)
+    );
+    assert_eq!(
+        signature_at_height("other", registered_at + 1),
+        signed("other", None)
+    );
+    assert_eq!(
+        signature_at_height("other", registered_at + 3),
+        signed("other", Some(2))
+    );
+    assert_eq!(
+        signature_at_height("other", u64::MAX),
+        signed("other", Some(2))
+    );
+
+    // Unknown manifestos have no state nor signatures
+    let err = query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::StateAtHeight {
+            manifesto_id: 2,
+            height: registered_at,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::ManifestoNotFound { manifesto_id: 2 });
+    let err = query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::SignatureAtHeight {
+            manifesto_id: 2,
+            signee: "other".to_string(),
+            height: registered_at,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::ManifestoNotFound { manifesto_id: 2 });
+}
+
+#[test]
+fn querying_signees() {
+    let mut deps = mock_dependencies(&[]);
+    setup_contract(deps.as_mut());
+
+    for signee in ["signee", "other"] {
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(signee, &[]),
+            sign_msg(MARTIAN_DATE, MARTIAN_TIME),
+        )
+        .unwrap();
+    }
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("other", &[]),
+        ExecuteMsg::WithdrawSignature {
+            manifesto_id: MANIFESTO_ID,
+        },
+    )
+    .unwrap();
+
+    let signee = |address: &str, is_signee| SigneeResponse {
+        address: Addr::unchecked(address),
+        is_signee,
+    };
+
+    let res = query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::IsSignee {
+            manifesto_id: MANIFESTO_ID,
+            address: "signee".to_string(),
+        },
+    )
+    .unwrap();
+    let res: SigneeResponse = from_binary(&res).unwrap();
+    assert_eq!(res, signee("signee", true));
+
+    // Withdrawn signees are no longer signees
+    let res = query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::AreSignees {
+            manifesto_id: MANIFESTO_ID,
+            addresses: vec![
+                "stranger".to_string(),
+                "signee".to_string(),
+                "other".to_string(),
+                "ab".to_string(),
+            ],
+        },
+    )
+    .unwrap();
+    let res: SigneesResponse = from_binary(&res).unwrap();
+    assert_eq!(
+        res.signees,
+        vec![
+            signee("stranger", false),
+            signee("signee", true),
+            signee("other", false),
+            signee("ab", false),
+        ]
+    );
+
+    // Invalid addresses are reported as not signed in batches only
+    let err = query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::IsSignee {
+            manifesto_id: MANIFESTO_ID,
+            address: "ab".to_string(),
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::Std(_)));
+
+    // Unknown manifestos fail
+    for msg in [
+        QueryMsg::IsSignee {
+            manifesto_id: 2,
+            address: "signee".to_string(),
+        },
+        QueryMsg::AreSignees {
+            manifesto_id: 2,
+            addresses: vec!["signee".to_string()],
+        },
+    ] {
+        let err = query(deps.as_ref(), mock_env(), msg).unwrap_err();
+        assert_eq!(err, ContractError::ManifestoNotFound { manifesto_id: 2 });
+    }
+
+    let err = query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::AreSignees {
+            manifesto_id: MANIFESTO_ID,
+            addresses: vec!["signee".to_string(); 101],
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::TooManyAddresses {
+            count: 101,
+            max_addresses: 100,
+        }
+    );
+}
+
+#[test]
+fn querying_signatures_by_date() {
+    let mut deps = mock_dependencies(&[]);
+    setup_contract(deps.as_mut());
+
+    let entries = [
+        ("aurora", "13 Mina 218"),
+        ("bruno", "14 Mina 218"),
+        ("carla", "28 Mina 218"),
+        ("dmitri", "1 Aries 218"),
+    ]
+    .iter()
+    .enumerate()
+    .map(|(i, (signee, martian_date))| ImportedSignature {
+        signee: signee.to_string(),
+        martian_date: martian_date.to_string(),
+        martian_time: MARTIAN_TIME.to_string(),
+        ordinal: i as u64 + 1,
+    })
+    .collect();
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        ExecuteMsg::ImportSignatures {
+            manifesto_id: MANIFESTO_ID,
+            entries,
+            mint_medals: false,
+        },
+    )
+    .unwrap();
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("signee", &[]),
+        sign_msg(MARTIAN_DATE, MARTIAN_TIME),
+    )
+    .unwrap();
+
+    let signatures_by_date = |start_after, limit| -> Vec<String> {
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::SignaturesByDate {
+                manifesto_id: MANIFESTO_ID,
+                date: MARTIAN_DATE.to_string(),
+                start_after,
+                limit,
+            },
+        )
+        .unwrap();
+        let res: SignaturesResponse = from_binary(&res).unwrap();
+        res.signatures
+            .into_iter()
+            .map(|signature| signature.signee)
+            .collect()
+    };
+    assert_eq!(signatures_by_date(None, None), vec!["bruno", "signee"]);
+    assert_eq!(signatures_by_date(None, Some(1)), vec!["bruno"]);
+    assert_eq!(signatures_by_date(Some(2), None), vec!["signee"]);
+
+    let histogram_msg = |from: &str, to: &str| QueryMsg::SignatureHistogram {
+        manifesto_id: MANIFESTO_ID,
+        from: from.to_string(),
+        to: to.to_string(),
+    };
+    let res = query(
+        deps.as_ref(),
+        mock_env(),
+        histogram_msg(MARTIAN_DATE, "1 Aries 218"),
+    )
+    .unwrap();
+    let res: SignatureHistogramResponse = from_binary(&res).unwrap();
+    let sol = |martian_date: &str, count| SolCount {
+        martian_date: martian_date.to_string(),
+        count,
+    };
+    assert_eq!(
+        res.sols,
+        vec![
+            sol("14 Mina 218", 2),
+            sol("28 Mina 218", 1),
+            sol("1 Aries 218", 1),
+        ]
+    );
+    // Months are counted in full
+    let month = |month: &str, count| MonthCount {
+        year: 218,
+        month: month.to_string(),
+        count,
+    };
+    assert_eq!(res.months, vec![month("Mina", 4), month("Aries", 1)]);
+
+    let err = query(
+        deps.as_ref(),
+        mock_env(),
+        histogram_msg("1 Aries 218", MARTIAN_DATE),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidDateRange {
+            from: "1 Aries 218".to_string(),
+            to: MARTIAN_DATE.to_string(),
+        }
+    );
+    // 668 sols in 218, 669 in 219
+    let err = query(
+        deps.as_ref(),
+        mock_env(),
+        histogram_msg("1 Mina 218", "1 Mina 220"),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::DateRangeTooLong {
+            sols: 1338,
+            max_sols: 669,
+        }
+    );
+
+    // Month keys of far future years don't overflow
+    let res = query(
+        deps.as_ref(),
+        mock_env(),
+        histogram_msg("1 Sagittarius 200000000", "2 Sagittarius 200000000"),
+    )
+    .unwrap();
+    let res: SignatureHistogramResponse = from_binary(&res).unwrap();
+    assert!(res.sols.is_empty() && res.months.is_empty());
+}
@@ -0,0 +1,198 @@
+use cosmwasm_std::{StdError, Uint128};
+use mars_community::darian::DarianError;
+use mars_community::pause::PauseError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    Darian(#[from] DarianError),
+
+    #[error("{0}")]
+    Pause(#[from] PauseError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("No admin proposal")]
+    NoAdminProposal {},
+
+    #[error("Admin proposal is already expired")]
+    AdminProposalAlreadyExpired {},
+
+    #[error("Admin proposal has expired")]
+    AdminProposalExpired {},
+
+    #[error("Manifesto {manifesto_id} does not exist")]
+    ManifestoNotFound { manifesto_id: u64 },
+
+    #[error("Version {version} of manifesto {manifesto_id} does not exist")]
+    VersionNotFound { manifesto_id: u64, version: u32 },
+
+    #[error("Invalid SHA-256 hash: {hash}")]
+    InvalidHash { hash: String },
+
+    #[error("Invalid background color: {color}, expected 6 hex digits")]
+    InvalidBackgroundColor { color: String },
+
+    #[error("MEDAL template must fill the {trait_type} trait, SVG images are rendered from it")]
+    MissingSvgTrait { trait_type: String },
+
+    #[error("Tier {tier} must start at ordinal 1 or later and end at or after its start")]
+    InvalidTierRange { tier: String },
+
+    #[error("Duplicate tier name: {tier}")]
+    DuplicateTierName { tier: String },
+
+    #[error("Ordinals of tier {tier} overlap with another tier")]
+    OverlappingTiers { tier: String },
+
+    #[error("Content hash does not match version {version} of manifesto {manifesto_id}")]
+    ContentHashMismatch { manifesto_id: u64, version: u32 },
+
+    #[error("Martian date and time must be provided together")]
+    IncompleteMartianDateTime {},
+
+    #[error("Martian date and time drift from block time by {drift} seconds, max allowed is {time_tolerance}")]
+    MartianDateTimeDrift { drift: u64, time_tolerance: u64 },
+
+    #[error("Too many addresses: {count}, max allowed is {max_addresses}")]
+    TooManyAddresses { count: usize, max_addresses: usize },
+
+    #[error("Invalid date range: {from} is after {to}")]
+    InvalidDateRange { from: String, to: String },
+
+    #[error("Date range too long: {sols} sols, max allowed is {max_sols}")]
+    DateRangeTooLong { sols: u64, max_sols: u64 },
+
+    #[error("Invalid integer key of {length} bytes, expected {expected}")]
+    InvalidIntegerKey { length: usize, expected: usize },
+
+    #[error("Signatures can no longer be imported")]
+    ImportFinalized {},
+
+    #[error("{signee} has already signed the Manifesto")]
+    DuplicateSignature { signee: String },
+
+    #[error("Unexpected ordinal {ordinal}, expected {expected}")]
+    UnexpectedOrdinal { ordinal: u64, expected: u64 },
+
+    #[error("User has already signed the Manifesto")]
+    AlreadySigned {},
+
+    #[error("User has not signed the Manifesto")]
+    NotSigned {},
+
+    #[error("User has withdrawn their signature from the Manifesto")]
+    SignatureWithdrawn {},
+
+    #[error("User has already withdrawn their signature")]
+    AlreadyWithdrawn {},
+
+    #[error("MEDAL {token_id} has already been minted")]
+    MedalAlreadyMinted { token_id: String },
+
+    #[error("Unknown reply id {id}")]
+    UnknownReplyId { id: u64 },
+
+    #[error("Max signee limit reached: {signees_count} of {max_signees_allowed} signees")]
+    MaxSigneesReached {
+        signees_count: u64,
+        max_signees_allowed: u64,
+    },
+
+    #[error("Duplicate signing phase id {phase_id}")]
+    DuplicatePhaseId { phase_id: u32 },
+
+    #[error("Signing phase {phase_id} ends before it starts")]
+    InvalidPhaseWindow { phase_id: u32 },
+
+    #[error("No signing phase is active")]
+    NoActivePhase {},
+
+    #[error("Max signee limit reached for signing phase {phase_id}: {signees_count} of {max_signees} signees")]
+    PhaseMaxSigneesReached {
+        phase_id: u32,
+        signees_count: u64,
+        max_signees: u64,
+    },
+
+    #[error("Merkle proof required for signing round {round}")]
+    MerkleProofRequired { round: u32 },
+
+    #[error("Signee is not eligible for signing round {round}")]
+    NotEligible { round: u32 },
+
+    #[error("Signing fee amount in {denom} must be positive")]
+    ZeroFeeAmount { denom: String },
+
+    #[error("Signing fee lists denom {denom} more than once")]
+    DuplicateFeeDenom { denom: String },
+
+    #[error("No funds are required")]
+    FundsNotRequired {},
+
+    #[error("Signing fee must be paid in one of the accepted denoms")]
+    MissingFee {},
+
+    #[error("Signing fee must be paid in a single denom")]
+    MultipleFeeDenoms {},
+
+    #[error("Denom {denom} is not accepted for the signing fee")]
+    UnacceptedDenom { denom: String },
+
+    #[error("Insufficient signing fee: sent {sent}{denom}, required {required}{denom}")]
+    InsufficientFee {
+        denom: String,
+        required: Uint128,
+        sent: Uint128,
+    },
+
+    #[error("Invalid public key")]
+    InvalidPubkey {},
+
+    #[error("Contract address {address} is not a bech32 address")]
+    InvalidContractAddress { address: String },
+
+    #[error("Invalid bech32 address prefix {prefix}")]
+    InvalidAddressPrefix { prefix: String },
+
+    #[error("Invalid nonce {nonce}, expected {expected}")]
+    InvalidNonce { nonce: u64, expected: u64 },
+
+    #[error("Invalid signature")]
+    InvalidSignature {},
+
+    #[error("Invalid contract version: {version}")]
+    InvalidContractVersion { version: String },
+
+    #[error("Cannot migrate from a different contract: {contract}")]
+    ContractNameMismatch { contract: String },
+
+    #[error("Cannot migrate from version {previous_version} to older version {new_version}")]
+    CannotDowngrade {
+        previous_version: String,
+        new_version: String,
+    },
+
+    #[error("Migrating from v1.0.0 requires the title and content hash of its manifesto")]
+    MissingLegacyManifesto {},
+
+    #[error("Signatures of v1.0.0 must be migrated before signing manifesto 1")]
+    LegacySignaturesPending {},
+
+    #[error("All signatures of v1.0.0 are migrated")]
+    LegacySignaturesMigrated {},
+
+    #[error("{signee} has no signature of v1.0.0 left to migrate")]
+    LegacySignatureNotFound { signee: String },
+
+    #[error("Invalid MEDAL token id of v1.0.0: {token_id}")]
+    InvalidLegacyTokenId { token_id: String },
+
+    #[error("MEDAL {token_id} was not minted for the signature of {signee}")]
+    LegacyMedalMismatch { signee: String, token_id: String },
+}
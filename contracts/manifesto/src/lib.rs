@@ -1,2 +1,5 @@
 pub mod contract;
+mod contract_tests;
+pub mod error;
+pub mod migrations;
 pub mod state;
@@ -0,0 +1,280 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use cosmwasm_std::{Addr, DepsMut, Env, Order, StdResult, Storage, Timestamp};
+use cw_storage_plus::{Item, Map, U32Key, U64Key};
+use semver::Version;
+
+use crate::contract::{count_signature_date, DEFAULT_TIME_TOLERANCE};
+use crate::error::ContractError;
+use crate::state::{
+    signatures, Config, Manifesto, ManifestoVersion, Signature, State, CONFIG, MANIFESTOS,
+    METADATA, SIGNEES, STATE, VERSIONS,
+};
+use mars_community::client::MedalContract;
+use mars_community::darian::{self, MartianDate, MartianTime};
+use mars_community::manifesto::{
+    LegacyMedal, MedalMetaData, MigrateMsg, MARTIAN_DATE_TRAIT, MARTIAN_TIME_TRAIT,
+};
+
+/// Version of contracts deployed before versions were stored with cw2
+pub const LEGACY_CONTRACT_VERSION: &str = "1.0.0";
+
+/// Id of the manifesto the single manifesto of v1.0.0 is moved to
+pub const LEGACY_MANIFESTO_ID: u64 = 1;
+
+/// Number of v1.0.0 signatures moved to the legacy manifesto, stored until all of them are
+pub const LEGACY_SIGNATURES_MIGRATED: Item<u64> = Item::new("legacy_signatures_migrated");
+
+/// Storage layout of v1.0.0, which hosted a single manifesto
+pub mod v1_0_0 {
+    use super::*;
+
+    pub const CONFIG: Item<Config> = Item::new("config");
+    pub const STATE: Item<State> = Item::new("state");
+    pub const METADATA: Map<&[u8], MedalMetaData> = Map::new("metadata");
+    pub const SIGNATURES: Map<&[u8], Signature> = Map::new("signatures");
+
+    #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+    pub struct Config {
+        pub medal_addr: Addr,
+        pub medal_redeem_addr: Addr,
+        pub max_signees_allowed: u64,
+        pub admin: Addr,
+    }
+
+    #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+    pub struct State {
+        pub signees_count: u64,
+    }
+
+    #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+    pub struct Signature {
+        pub signee: Addr,
+        pub martian_date: String,
+        pub martian_time: String,
+    }
+}
+
+/// Runs, in order, the state migrations of every version released after `previous_version`
+pub fn migrate(
+    storage: &mut dyn Storage,
+    env: &Env,
+    previous_version: &Version,
+    msg: MigrateMsg,
+) -> Result<(), ContractError> {
+    if *previous_version < Version::new(2, 0, 0) {
+        let (title, content_hash) = match (msg.title, msg.content_hash) {
+            (Some(title), Some(content_hash)) => (title, content_hash),
+            _ => return Err(ContractError::MissingLegacyManifesto {}),
+        };
+        migrate_from_v1_0_0(
+            storage,
+            title,
+            ManifestoVersion {
+                version: 1,
+                content_hash,
+                uri: msg.uri,
+                published_at: env.block.time,
+            },
+            DEFAULT_TIME_TOLERANCE,
+            env.block.height,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Moves the manifesto of v1.0.0 to manifesto id 1, whose text is `version`. Its signatures
+/// are left in place and moved in batches with `migrate_legacy_signatures`, so that the gas
+/// of the migration does not grow with the number of signees.
+/// Snapshots of the signing state start at `height`, the block of the migration.
+pub fn migrate_from_v1_0_0(
+    storage: &mut dyn Storage,
+    title: String,
+    version: ManifestoVersion,
+    time_tolerance: u64,
+    height: u64,
+) -> StdResult<()> {
+    let legacy_config = v1_0_0::CONFIG.load(storage)?;
+    let legacy_state = v1_0_0::STATE.load(storage)?;
+    let legacy_metadata =
+        v1_0_0::METADATA.may_load(storage, legacy_config.medal_addr.as_bytes())?;
+    v1_0_0::METADATA.remove(storage, legacy_config.medal_addr.as_bytes());
+    v1_0_0::STATE.remove(storage);
+
+    CONFIG.save(
+        storage,
+        &Config {
+            admin: legacy_config.admin,
+            time_tolerance,
+            manifestos_count: LEGACY_MANIFESTO_ID,
+            signing_fee: None,
+        },
+    )?;
+    MANIFESTOS.save(
+        storage,
+        U64Key::new(LEGACY_MANIFESTO_ID),
+        &Manifesto {
+            id: LEGACY_MANIFESTO_ID,
+            title,
+            version: version.version,
+            max_signees_allowed: legacy_config.max_signees_allowed,
+            medal_addr: legacy_config.medal_addr,
+            medal_redeem_addr: legacy_config.medal_redeem_addr,
+        },
+    )?;
+    VERSIONS.save(
+        storage,
+        (
+            U64Key::new(LEGACY_MANIFESTO_ID),
+            U32Key::new(version.version),
+        ),
+        &version,
+    )?;
+    STATE.save(
+        storage,
+        U64Key::new(LEGACY_MANIFESTO_ID),
+        &State {
+            signees_count: legacy_state.signees_count,
+            withdrawn_count: 0,
+        },
+        height,
+    )?;
+    if let Some(metadata) = legacy_metadata {
+        METADATA.save(storage, U64Key::new(LEGACY_MANIFESTO_ID), &metadata)?;
+    }
+    if has_legacy_signatures(storage) {
+        LEGACY_SIGNATURES_MIGRATED.save(storage, &0)?;
+    }
+
+    Ok(())
+}
+
+/// Moves the v1.0.0 signatures of the given signees to the legacy manifesto. Returns
+/// `LegacySignaturesMigrated` once all of them are.
+///
+/// v1.0.0 did not record signing order, but minted the MEDAL of each signee with their signing
+/// order as token id. MEDALs may have changed hands since, so the token id of each signee is
+/// taken from the mints of v1.0.0 and becomes their ordinal. MEDALs still found in the MEDAL
+/// contract must carry the Martian date and time of the signature; redeemed ones can't be
+/// checked.
+pub fn migrate_legacy_signatures(
+    deps: DepsMut,
+    height: u64,
+    entries: Vec<LegacyMedal>,
+) -> Result<(), ContractError> {
+    let mut migrated = LEGACY_SIGNATURES_MIGRATED
+        .may_load(deps.storage)?
+        .ok_or(ContractError::LegacySignaturesMigrated {})?;
+    let manifesto = MANIFESTOS.load(deps.storage, U64Key::new(LEGACY_MANIFESTO_ID))?;
+    let legacy_count = STATE
+        .load(deps.storage, U64Key::new(LEGACY_MANIFESTO_ID))?
+        .signees_count;
+    let medal = MedalContract(manifesto.medal_addr);
+
+    for entry in entries {
+        let signee = deps.api.addr_validate(&entry.signee)?;
+        let legacy_signature = v1_0_0::SIGNATURES
+            .may_load(deps.storage, signee.as_bytes())?
+            .ok_or_else(|| ContractError::LegacySignatureNotFound {
+                signee: entry.signee.clone(),
+            })?;
+        let ordinal = entry
+            .token_id
+            .parse::<u64>()
+            .ok()
+            .filter(|ordinal| (1..=legacy_count).contains(ordinal))
+            .ok_or_else(|| ContractError::InvalidLegacyTokenId {
+                token_id: entry.token_id.clone(),
+            })?;
+        if let Ok(nft_info) = medal.nft_info(&deps.querier, entry.token_id.clone()) {
+            let trait_value = |trait_type: &str| {
+                nft_info
+                    .extension
+                    .as_ref()
+                    .and_then(|metadata| metadata.attributes.as_ref())
+                    .and_then(|traits| traits.iter().find(|t| t.trait_type == trait_type))
+                    .map(|t| t.value.clone())
+            };
+            if trait_value(MARTIAN_DATE_TRAIT) != Some(legacy_signature.martian_date.clone())
+                || trait_value(MARTIAN_TIME_TRAIT) != Some(legacy_signature.martian_time.clone())
+            {
+                return Err(ContractError::LegacyMedalMismatch {
+                    signee: entry.signee,
+                    token_id: entry.token_id,
+                });
+            }
+        }
+
+        let signature_ = Signature {
+            manifesto_id: LEGACY_MANIFESTO_ID,
+            signee: signee.clone(),
+            martian_date: legacy_signature.martian_date.clone(),
+            martian_time: legacy_signature.martian_time.clone(),
+            ordinal,
+            medal_token_id: Some(entry.token_id),
+            version: manifesto.version,
+            block_height: 0,
+            signed_at: signed_at(
+                &legacy_signature.martian_date,
+                &legacy_signature.martian_time,
+            )
+            .unwrap_or_else(|| Timestamp::from_nanos(0)),
+            withdrawn_at: None,
+        };
+        SIGNEES.save(
+            deps.storage,
+            (U64Key::new(LEGACY_MANIFESTO_ID), signee.as_bytes()),
+            &ordinal,
+            height,
+        )?;
+        // The ordinal index rejects token ids given to several signees
+        signatures().save(
+            deps.storage,
+            (U64Key::new(LEGACY_MANIFESTO_ID), signee.as_bytes()),
+            &signature_,
+        )?;
+        if let Ok(martian_date) = signature_.martian_date.parse::<MartianDate>() {
+            count_signature_date(deps.storage, LEGACY_MANIFESTO_ID, &martian_date)?;
+        }
+        v1_0_0::SIGNATURES.remove(deps.storage, signee.as_bytes());
+        migrated += 1;
+    }
+
+    if has_legacy_signatures(deps.storage) {
+        LEGACY_SIGNATURES_MIGRATED.save(deps.storage, &migrated)?;
+    } else {
+        LEGACY_SIGNATURES_MIGRATED.remove(deps.storage);
+    }
+
+    Ok(())
+}
+
+/// Ordinals of the legacy manifesto are only known once all of its signatures are migrated, so
+/// it can't be signed before
+pub fn assert_legacy_signatures_migrated(
+    storage: &dyn Storage,
+    manifesto_id: u64,
+) -> Result<(), ContractError> {
+    if manifesto_id == LEGACY_MANIFESTO_ID
+        && LEGACY_SIGNATURES_MIGRATED.may_load(storage)?.is_some()
+    {
+        return Err(ContractError::LegacySignaturesPending {});
+    }
+    Ok(())
+}
+
+fn has_legacy_signatures(storage: &dyn Storage) -> bool {
+    v1_0_0::SIGNATURES
+        .keys(storage, None, None, Order::Ascending)
+        .next()
+        .is_some()
+}
+
+/// Earth time at which a v1.0.0 signature was signed, if its Martian date and time are valid
+fn signed_at(martian_date: &str, martian_time: &str) -> Option<Timestamp> {
+    let date = martian_date.parse::<MartianDate>().ok()?;
+    let time = martian_time.parse::<MartianTime>().ok()?;
+    darian::to_timestamp(&date, &time).ok()
+}
@@ -1,42 +1,181 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use cosmwasm_std::Addr;
-use cw_storage_plus::{Item, Map};
+use cosmwasm_std::{Addr, Timestamp};
+use cw20::Expiration;
+use cw_storage_plus::{
+    Index, IndexList, IndexedMap, Item, Map, SnapshotMap, Strategy, U32Key, U64Key, UniqueIndex,
+};
 
-use mars_community::manifesto::MedalMetaData;
+use mars_community::darian::MartianDate;
+use mars_community::manifesto::{
+    MedalMetaData, MedalTemplate, MedalTier, SigningFee, SigningPhase,
+};
 
 pub const CONFIG: Item<Config> = Item::new("config");
-pub const STATE: Item<State> = Item::new("state");
-pub const METADATA: Map<&[u8], MedalMetaData> = Map::new("metadata");
-pub const SIGNATURES: Map<&[u8], Signature> = Map::new("signatures");
+/// Admin proposed by the current one, waiting to claim the role
+pub const ADMIN_PROPOSAL: Item<AdminProposal> = Item::new("admin_proposal");
+/// Manifestos per manifesto id
+pub const MANIFESTOS: Map<U64Key, Manifesto> = Map::new("manifestos");
+/// Signing state per manifesto id, snapshotted every block
+pub const STATE: SnapshotMap<U64Key, State> = SnapshotMap::new(
+    "manifesto_state",
+    "manifesto_state__checkpoints",
+    "manifesto_state__changelog",
+    Strategy::EveryBlock,
+);
+/// Ordinal of the signature per manifesto id and signee address, snapshotted every block.
+/// Removed once the signature is withdrawn
+pub const SIGNEES: SnapshotMap<(U64Key, &[u8]), u64> = SnapshotMap::new(
+    "manifesto_signees",
+    "manifesto_signees__checkpoints",
+    "manifesto_signees__changelog",
+    Strategy::EveryBlock,
+);
+/// Versions of the manifesto text per manifesto id and version
+pub const VERSIONS: Map<(U64Key, U32Key), ManifestoVersion> = Map::new("manifesto_versions");
+/// MEDAL metadata per manifesto id
+pub const METADATA: Map<U64Key, MedalMetaData> = Map::new("medal_metadata");
+/// Template of the MEDAL traits per manifesto id, the default one if missing
+pub const MEDAL_TEMPLATES: Map<U64Key, MedalTemplate> = Map::new("medal_templates");
+/// MEDAL tiers per manifesto id, sorted by ordinal
+pub const MEDAL_TIERS: Map<U64Key, Vec<MedalTier>> = Map::new("medal_tiers");
+/// Number of MEDALs minted per manifesto id and tier name
+pub const TIER_MINTS: Map<(U64Key, &str), u64> = Map::new("tier_mints");
+/// Number of signatures per manifesto id and sol, see `sol_key`
+pub const SOL_SIGNATURES: Map<(U64Key, U64Key), u64> = Map::new("sol_signatures");
+/// Number of signatures per manifesto id and Darian month, see `month_key`
+pub const MONTH_SIGNATURES: Map<(U64Key, U64Key), u64> = Map::new("month_signatures");
+/// Signing phases per manifesto id. Signing is open at all times if there are none
+pub const SIGNING_PHASES: Map<U64Key, Vec<SigningPhase>> = Map::new("signing_phases");
+/// Number of signees per manifesto id and signing phase id
+pub const PHASE_SIGNEES: Map<(U64Key, U32Key), u64> = Map::new("phase_signees");
+/// Next nonce of relayed signatures per signee address
+pub const NONCES: Map<&[u8], u64> = Map::new("nonces");
+/// Hex encoded Merkle root of the allowlist per manifesto id and signing round
+pub const MERKLE_ROOTS: Map<(U64Key, U32Key), String> = Map::new("merkle_roots");
+/// Signatures whose MEDALs are being minted, in the order of their mint submessages, until the
+/// replies of the mints are handled
+pub const PENDING_MINTS: Item<Vec<PendingMint>> = Item::new("pending_mints");
+/// Set once importing signatures is disabled for good
+pub const IMPORT_FINALIZED: Item<bool> = Item::new("import_finalized");
+
+pub struct SignatureIndexes<'a> {
+    pub ordinal: UniqueIndex<'a, (U64Key, U64Key), Signature>,
+    pub date: UniqueIndex<'a, (U64Key, U64Key, U64Key), Signature>,
+}
+
+impl<'a> IndexList<Signature> for SignatureIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<Signature>> + '_> {
+        let v: Vec<&dyn Index<Signature>> = vec![&self.ordinal, &self.date];
+        Box::new(v.into_iter())
+    }
+}
+
+/// Signatures stored by manifesto id and signee address, indexed by signing order and by
+/// Martian date then signing order
+pub fn signatures<'a>() -> IndexedMap<'a, (U64Key, &'a [u8]), Signature, SignatureIndexes<'a>> {
+    let indexes = SignatureIndexes {
+        ordinal: UniqueIndex::new(
+            |s| (U64Key::new(s.manifesto_id), U64Key::new(s.ordinal)),
+            "manifesto_signatures__ordinal",
+        ),
+        date: UniqueIndex::new(
+            |s| {
+                (
+                    U64Key::new(s.manifesto_id),
+                    U64Key::new(sol_key(&s.martian_date)),
+                    U64Key::new(s.ordinal),
+                )
+            },
+            "manifesto_signatures__date",
+        ),
+    };
+    IndexedMap::new("manifesto_signatures", indexes)
+}
+
+/// Key of a Martian date in the date index and `SOL_SIGNATURES`: the number of sols since the
+/// Darian epoch. Dates which cannot be parsed, only found in signatures migrated from v1.0.0,
+/// are sorted last
+pub fn sol_key(martian_date: &str) -> u64 {
+    martian_date
+        .parse::<MartianDate>()
+        .map_or(u64::MAX, |date| date.sols_since_epoch())
+}
+
+/// Key of the Darian month of a date in `MONTH_SIGNATURES`: `year * 24 + month - 1`
+pub fn month_key(date: &MartianDate) -> u64 {
+    date.year as u64 * 24 + date.month as u64 - 1
+}
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct Config {
+    pub admin: Addr,
+    /// Max drift (in seconds) allowed between a signee's Martian date and time and block time
+    pub time_tolerance: u64,
+    /// Number of manifestos registered, also the id of the last one
+    pub manifestos_count: u64,
+    /// Fee charged to new signees. Signing is free if None
+    pub signing_fee: Option<SigningFee>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AdminProposal {
+    pub new_admin: Addr,
+    pub expires: Expiration,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Manifesto {
+    pub id: u64,
+    pub title: String,
+    /// Current version of the manifesto text
+    pub version: u32,
+    pub max_signees_allowed: u64,
     pub medal_addr: Addr,
     pub medal_redeem_addr: Addr,
-    pub max_signees_allowed: u64,
-    pub admin: Addr,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ManifestoVersion {
+    /// Version number, starting at 1
+    pub version: u32,
+    /// Hex encoded SHA-256 hash of the manifesto text
+    pub content_hash: String,
+    /// Where the manifesto text can be found
+    pub uri: Option<String>,
+    pub published_at: Timestamp,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct State {
+    /// Number of signatures, including withdrawn ones
     pub signees_count: u64,
+    /// Number of signatures withdrawn by their signee
+    pub withdrawn_count: u64,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct Signature {
+    pub manifesto_id: u64,
     pub signee: Addr,
     pub martian_date: String,
     pub martian_time: String,
+    /// Signing order, starting at 1
+    pub ordinal: u64,
+    /// MEDAL token minted to the signee, confirmed by the MEDAL contract. None while the mint
+    /// is pending, i.e. until it is confirmed or if it failed
+    pub medal_token_id: Option<String>,
+    /// Version of the manifesto text the signee agreed to
+    pub version: u32,
+    pub block_height: u64,
+    pub signed_at: Timestamp,
+    /// Set once the signee withdraws their signature
+    pub withdrawn_at: Option<Timestamp>,
 }
 
-impl Default for Signature {
-    fn default() -> Self {
-        Signature {
-            signee: Addr::unchecked("".to_string()),
-            martian_date: "".to_string(),
-            martian_time: "".to_string(),
-        }
-    }
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PendingMint {
+    pub manifesto_id: u64,
+    pub signee: Addr,
 }
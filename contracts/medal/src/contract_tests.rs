@@ -1,17 +1,33 @@
 #![cfg(test)]
-use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
-use cosmwasm_std::{from_binary, to_binary, CosmosMsg, DepsMut, Empty, Response, WasmMsg};
+use cosmwasm_std::testing::{
+    mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage,
+};
+use cosmwasm_std::{
+    coin, coins, from_binary, to_binary, Addr, BankMsg, ContractResult, CosmosMsg, Decimal,
+    DepsMut, Empty, OwnedDeps, Response, SystemResult, Uint128, WasmMsg,
+};
+use terra_cosmwasm::{TaxCapResponse, TaxRateResponse, TerraQuery, TerraQueryWrapper};
 
+use cw2::{get_contract_version, set_contract_version};
 use cw721::{
     ApprovedForAllResponse, ContractInfoResponse, Cw721Query, Cw721ReceiveMsg, Expiration,
     NftInfoResponse, OwnerOfResponse,
 };
 
+use mars_community::events::{self, MedalMintData, MedalRedeemData};
+use mars_community::manifesto::RegionFee;
+use mars_community::metadata::{Metadata, Trait};
+use mars_community::pause::{PauseError, PauseFlag, PauseFlags, PausedResponse};
+
+use crate::state::{MedalMetaData, Redemption, RedemptionFee};
+use crate::svg::MAX_SVG_TEMPLATE_LENGTH;
 use crate::{
-    ContractError, Cw721Contract, ExecuteMsg, Extension, InstantiateMsg, MintMsg, QueryMsg,
+    ContractError, Cw721Contract, ExecuteMsg, Extension, InstantiateMsg, MigrateMsg, MintMsg,
+    QueryMsg,
 };
 
 const MINTER: &str = "merlin";
+const ADMIN: &str = "arthur";
 const CONTRACT_NAME: &str = "Magic Power";
 const SYMBOL: &str = "MGK";
 
@@ -21,6 +37,7 @@ fn setup_contract(deps: DepsMut<'_>) -> Cw721Contract<'static, Extension, Empty>
         name: CONTRACT_NAME.to_string(),
         symbol: SYMBOL.to_string(),
         minter: String::from(MINTER),
+        admin: String::from(ADMIN),
     };
     let info = mock_info("creator", &[]);
     let res = contract.instantiate(deps, mock_env(), info, msg).unwrap();
@@ -28,6 +45,29 @@ fn setup_contract(deps: DepsMut<'_>) -> Cw721Contract<'static, Extension, Empty>
     contract
 }
 
+/// Dependencies answering Terra tax queries with a 0.1% rate and a cap of `tax_cap`
+fn mock_dependencies_with_tax(
+    tax_cap: u128,
+) -> OwnedDeps<MockStorage, MockApi, MockQuerier<TerraQueryWrapper>> {
+    let querier = MockQuerier::new(&[]).with_custom_handler(move |query: &TerraQueryWrapper| {
+        let res = match &query.query_data {
+            TerraQuery::TaxRate {} => to_binary(&TaxRateResponse {
+                rate: Decimal::permille(1),
+            }),
+            TerraQuery::TaxCap { .. } => to_binary(&TaxCapResponse {
+                cap: tax_cap.into(),
+            }),
+            q => panic!("unexpected terra query: {:?}", q),
+        };
+        SystemResult::Ok(ContractResult::Ok(res.unwrap()))
+    });
+    OwnedDeps {
+        storage: MockStorage::default(),
+        api: MockApi::default(),
+        querier,
+    }
+}
+
 #[test]
 fn proper_instantiation() {
     let mut deps = mock_dependencies(&[]);
@@ -37,6 +77,7 @@ fn proper_instantiation() {
         name: CONTRACT_NAME.to_string(),
         symbol: SYMBOL.to_string(),
         minter: String::from(MINTER),
+        admin: String::from(ADMIN),
     };
     let info = mock_info("creator", &[]);
 
@@ -78,8 +119,8 @@ fn minting() {
     let mint_msg = ExecuteMsg::Mint(MintMsg::<Extension> {
         token_id: token_id.clone(),
         owner: String::from("medusa"),
-        name: name.clone(),
-        description: Some(description.clone()),
+        name,
+        description: Some(description),
         image: None,
         extension: None,
     });
@@ -93,9 +134,19 @@ fn minting() {
 
     // minter can mint
     let allowed = mock_info(MINTER, &[]);
-    let _ = contract
+    let res = contract
         .execute(deps.as_mut(), mock_env(), allowed, mint_msg)
         .unwrap();
+    assert_eq!(
+        res.events,
+        vec![events::medal_mint_event(
+            env!("CARGO_PKG_VERSION"),
+            &token_id,
+            "medusa"
+        )]
+    );
+    let data: MedalMintData = from_binary(&res.data.unwrap()).unwrap();
+    assert_eq!(data.token_id, token_id);
 
     // ensure num tokens increases
     let count = contract.num_tokens(deps.as_ref()).unwrap();
@@ -111,9 +162,7 @@ fn minting() {
     assert_eq!(
         info,
         NftInfoResponse::<Extension> {
-            name,
-            description,
-            image: None,
+            token_uri: None,
             extension: None,
         }
     );
@@ -202,6 +251,12 @@ fn transferring_nft() {
     assert_eq!(
         res,
         Response::new()
+            .add_event(events::medal_transfer_event(
+                env!("CARGO_PKG_VERSION"),
+                &token_id,
+                &Addr::unchecked("venus"),
+                &Addr::unchecked("random"),
+            ))
             .add_attribute("action", "transfer_nft")
             .add_attribute("sender", "venus")
             .add_attribute("recipient", "random")
@@ -271,6 +326,12 @@ fn sending_nft() {
         res,
         Response::new()
             .add_message(expected)
+            .add_event(events::medal_transfer_event(
+                env!("CARGO_PKG_VERSION"),
+                &token_id,
+                &Addr::unchecked("venus"),
+                &Addr::unchecked("another_contract"),
+            ))
             .add_attribute("action", "send_nft")
             .add_attribute("sender", "venus")
             .add_attribute("recipient", "another_contract")
@@ -613,9 +674,9 @@ fn query_tokens_by_owner() {
 
     // Mint a couple tokens (from the same owner)
     let token_id1 = "grow1".to_string();
-    let demeter = String::from("Demeter");
+    let demeter = String::from("demeter");
     let token_id2 = "grow2".to_string();
-    let ceres = String::from("Ceres");
+    let ceres = String::from("ceres");
     let token_id3 = "sing".to_string();
 
     let mint_msg = ExecuteMsg::Mint(MintMsg::<Extension> {
@@ -687,3 +748,791 @@ fn query_tokens_by_owner() {
         .unwrap();
     assert_eq!(&by_demeter[1..], &tokens.tokens[..]);
 }
+
+#[test]
+fn burning_medal() {
+    let mut deps = mock_dependencies(&[]);
+    let contract = setup_contract(deps.as_mut());
+
+    for (token_id, owner) in [("1", "demeter"), ("2", "ceres")] {
+        let mint_msg = ExecuteMsg::Mint(MintMsg::<Extension> {
+            token_id: token_id.to_string(),
+            owner: owner.to_string(),
+            name: "MEDAL #".to_string() + token_id,
+            description: None,
+            image: None,
+            extension: None,
+        });
+        contract
+            .execute(deps.as_mut(), mock_env(), mock_info(MINTER, &[]), mint_msg)
+            .unwrap();
+    }
+
+    // only the minter can burn
+    let burn_msg = ExecuteMsg::BurnMedal {
+        token_id: "1".to_string(),
+        owner: "demeter".to_string(),
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("demeter", &[]),
+            burn_msg.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    let res = contract
+        .execute(deps.as_mut(), mock_env(), mock_info(MINTER, &[]), burn_msg)
+        .unwrap();
+    assert_eq!("true", res.attributes[3].value);
+    let _ = contract
+        .owner_of(deps.as_ref(), mock_env(), "1".to_string(), false)
+        .unwrap_err();
+    assert_eq!(1, contract.num_tokens(deps.as_ref()).unwrap().count);
+
+    // burning a MEDAL which is gone or belongs to someone else does nothing
+    for (token_id, owner) in [("1", "demeter"), ("2", "demeter")] {
+        let burn_msg = ExecuteMsg::BurnMedal {
+            token_id: token_id.to_string(),
+            owner: owner.to_string(),
+        };
+        let res = contract
+            .execute(deps.as_mut(), mock_env(), mock_info(MINTER, &[]), burn_msg)
+            .unwrap();
+        assert_eq!("false", res.attributes[3].value);
+    }
+    let owner = contract
+        .owner_of(deps.as_ref(), mock_env(), "2".to_string(), false)
+        .unwrap();
+    assert_eq!("ceres", owner.owner);
+}
+
+#[test]
+fn pausing() {
+    let mut deps = mock_dependencies(&[]);
+    let contract = setup_contract(deps.as_mut());
+
+    let mint_msg = |token_id: &str| {
+        ExecuteMsg::Mint(MintMsg::<Extension> {
+            token_id: token_id.to_string(),
+            owner: String::from("venus"),
+            name: "MEDAL #".to_string() + token_id,
+            description: None,
+            image: None,
+            extension: None,
+        })
+    };
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(MINTER, &[]),
+            mint_msg("1"),
+        )
+        .unwrap();
+
+    // only the admin can set the guardian
+    let guardian_msg = ExecuteMsg::UpdateGuardian {
+        guardian: Some(String::from("lancelot")),
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(MINTER, &[]),
+            guardian_msg.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, ContractError::Pause(PauseError::Unauthorized {}));
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ADMIN, &[]),
+            guardian_msg,
+        )
+        .unwrap();
+
+    // random cannot pause, the guardian can
+    let pause_msg = ExecuteMsg::Pause {
+        flags: vec![
+            PauseFlag::Minting,
+            PauseFlag::Transfers,
+            PauseFlag::Redemption,
+        ],
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("random", &[]),
+            pause_msg.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, ContractError::Pause(PauseError::Unauthorized {}));
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("lancelot", &[]),
+            pause_msg,
+        )
+        .unwrap();
+    assert_eq!("pause", res.attributes[0].value);
+
+    let res: PausedResponse = from_binary(
+        &contract
+            .query(deps.as_ref(), mock_env(), QueryMsg::Paused {})
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        res,
+        PausedResponse {
+            guardian: Some(Addr::unchecked("lancelot")),
+            paused: PauseFlags {
+                signing: false,
+                minting: true,
+                transfers: true,
+                redemption: true,
+            },
+        }
+    );
+
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(MINTER, &[]),
+            mint_msg("2"),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Pause(PauseError::Paused {
+            flag: PauseFlag::Minting
+        })
+    );
+
+    let transfer_msg = ExecuteMsg::TransferNft {
+        recipient: String::from("random"),
+        token_id: "1".to_string(),
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("venus", &[]),
+            transfer_msg.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Pause(PauseError::Paused {
+            flag: PauseFlag::Transfers
+        })
+    );
+
+    let redeem_msg = ExecuteMsg::RedeemMedal {
+        token_id: "1".to_string(),
+        region: None,
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("venus", &[]),
+            redeem_msg,
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Pause(PauseError::Paused {
+            flag: PauseFlag::Redemption
+        })
+    );
+    // the guardian cannot unpause, the admin can
+    let unpause_msg = ExecuteMsg::Unpause {
+        flags: vec![PauseFlag::Transfers],
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("lancelot", &[]),
+            unpause_msg.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, ContractError::Pause(PauseError::Unauthorized {}));
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ADMIN, &[]),
+            unpause_msg,
+        )
+        .unwrap();
+
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("venus", &[]),
+            transfer_msg,
+        )
+        .unwrap();
+    let _ = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(MINTER, &[]),
+            mint_msg("2"),
+        )
+        .unwrap_err();
+}
+
+#[test]
+fn rendering_svg_image() {
+    let mut deps = mock_dependencies(&[]);
+    let contract = setup_contract(deps.as_mut());
+
+    let trait_ = |trait_type: &str, value: &str| Trait {
+        display_type: None,
+        trait_type: trait_type.to_string(),
+        value: value.to_string(),
+    };
+    let extension = Some(Metadata {
+        attributes: Some(vec![
+            trait_("martian_date", "14 Mina 218"),
+            trait_("martian_time", "15:40:34 MTC"),
+        ]),
+        ..Metadata::default()
+    });
+    let mint_msg = ExecuteMsg::Mint(MintMsg::<Extension> {
+        token_id: "7".to_string(),
+        owner: String::from("venus"),
+        name: "MEDAL <#7>".to_string(),
+        description: None,
+        image: Some("ipfs://medal".to_string()),
+        extension: extension.clone(),
+    });
+    contract
+        .execute(deps.as_mut(), mock_env(), mock_info(MINTER, &[]), mint_msg)
+        .unwrap();
+
+    // only the minter can set the template
+    let template = "<svg><text>{{name}}</text><text>{{ordinal}}</text>\
+        <text>{{martian_date}} {{martian_time}}</text></svg>";
+    let template_msg = ExecuteMsg::UpdateSvgTemplate {
+        template: Some(template.to_string()),
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("venus", &[]),
+            template_msg.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    // the template is size-limited
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(MINTER, &[]),
+            ExecuteMsg::UpdateSvgTemplate {
+                template: Some("a".repeat(MAX_SVG_TEMPLATE_LENGTH + 1)),
+            },
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::SvgTemplateTooLong {
+            length: MAX_SVG_TEMPLATE_LENGTH + 1,
+            max_length: MAX_SVG_TEMPLATE_LENGTH,
+        }
+    );
+
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(MINTER, &[]),
+            template_msg,
+        )
+        .unwrap();
+
+    let expected_svg = "<svg><text>MEDAL &lt;#7&gt;</text><text>7</text>\
+        <text>14 Mina 218 15:40:34 MTC</text></svg>";
+    let info = contract.nft_info(deps.as_ref(), "7".to_string()).unwrap();
+    assert_eq!(Some("ipfs://medal".to_string()), info.token_uri);
+    assert_eq!(
+        Some(expected_svg.to_string()),
+        info.extension.unwrap().image_data
+    );
+    let info = contract
+        .all_nft_info(deps.as_ref(), mock_env(), "7".to_string(), false)
+        .unwrap();
+    assert_eq!(
+        Some(expected_svg.to_string()),
+        info.info.extension.unwrap().image_data
+    );
+
+    // tokens keep their stored metadata once the template is removed
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(MINTER, &[]),
+            ExecuteMsg::UpdateSvgTemplate { template: None },
+        )
+        .unwrap();
+    let info = contract.nft_info(deps.as_ref(), "7".to_string()).unwrap();
+    assert_eq!(extension, info.extension);
+}
+
+#[test]
+fn redeeming_medal() {
+    let mut deps = mock_dependencies(&[]);
+    let contract = setup_contract(deps.as_mut());
+
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(MINTER, &[]),
+            ExecuteMsg::UpdateMedalRedeemConfig {
+                medal_redeem_addr: "medal_redeem".to_string(),
+                metadata: MedalMetaData {
+                    name_prefix: "R-MEDAL".to_string(),
+                    description: "Redeemed MEDAL".to_string(),
+                    image: "ipfs://image".to_string(),
+                    token_uri: "ipfs://token".to_string(),
+                },
+            },
+        )
+        .unwrap();
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(MINTER, &[]),
+            ExecuteMsg::Mint(MintMsg::<Extension> {
+                token_id: "7".to_string(),
+                owner: "venus".to_string(),
+                name: "MEDAL #7".to_string(),
+                description: None,
+                image: None,
+                extension: None,
+            }),
+        )
+        .unwrap();
+
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("venus", &[]),
+            ExecuteMsg::RedeemMedal {
+                token_id: "7".to_string(),
+                region: None,
+            },
+        )
+        .unwrap();
+
+    // the first R-MEDAL is minted for the MEDAL, which is burnt
+    assert_eq!(
+        res.events,
+        vec![events::medal_redeem_event(
+            env!("CARGO_PKG_VERSION"),
+            "7",
+            &Addr::unchecked("venus"),
+            "1"
+        )]
+    );
+    let data: MedalRedeemData = from_binary(&res.data.unwrap()).unwrap();
+    assert_eq!(
+        data,
+        MedalRedeemData {
+            token_id: "7".to_string(),
+            redeemed_token_id: "1".to_string(),
+        }
+    );
+    assert_eq!(contract.num_tokens(deps.as_ref()).unwrap().count, 0);
+}
+
+#[test]
+fn paying_redemption_fee() {
+    let mut deps = mock_dependencies_with_tax(1_000_000);
+    let contract = setup_contract(deps.as_mut());
+
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(MINTER, &[]),
+            ExecuteMsg::UpdateMedalRedeemConfig {
+                medal_redeem_addr: "medal_redeem".to_string(),
+                metadata: MedalMetaData {
+                    name_prefix: "R-MEDAL".to_string(),
+                    description: "Redeemed MEDAL".to_string(),
+                    image: "ipfs://image".to_string(),
+                    token_uri: "ipfs://token".to_string(),
+                },
+            },
+        )
+        .unwrap();
+    for token_id in ["7", "8"] {
+        contract
+            .execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(MINTER, &[]),
+                ExecuteMsg::Mint(MintMsg::<Extension> {
+                    token_id: token_id.to_string(),
+                    owner: "venus".to_string(),
+                    name: format!("MEDAL #{}", token_id),
+                    description: None,
+                    image: None,
+                    extension: None,
+                }),
+            )
+            .unwrap();
+    }
+
+    let update_fee = |regions: Vec<RegionFee>| ExecuteMsg::UpdateRedemptionFee {
+        recipient: "treasury".to_string(),
+        regions,
+    };
+    let eu = RegionFee {
+        region: "EU".to_string(),
+        amounts: vec![coin(5_000_000, "uusd"), coin(100_000, "uluna")],
+    };
+    let us = RegionFee {
+        region: "US".to_string(),
+        amounts: coins(4_000_000, "uusd"),
+    };
+    let mars = RegionFee {
+        region: "Mars".to_string(),
+        amounts: vec![],
+    };
+
+    // only the minter can set the fee, which must be valid
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("venus", &[]),
+            update_fee(vec![eu.clone()]),
+        )
+        .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    let invalid_fees = vec![
+        (
+            vec![eu.clone(), eu.clone()],
+            ContractError::DuplicateRegion {
+                region: "EU".to_string(),
+            },
+        ),
+        (
+            vec![RegionFee {
+                region: "US".to_string(),
+                amounts: coins(0, "uusd"),
+            }],
+            ContractError::ZeroFeeAmount {
+                denom: "uusd".to_string(),
+            },
+        ),
+        (
+            vec![RegionFee {
+                region: "US".to_string(),
+                amounts: vec![coin(1, "uusd"), coin(2, "uusd")],
+            }],
+            ContractError::DuplicateFeeDenom {
+                region: "US".to_string(),
+                denom: "uusd".to_string(),
+            },
+        ),
+    ];
+    for (regions, expected) in invalid_fees {
+        let err = contract
+            .execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(MINTER, &[]),
+                update_fee(regions),
+            )
+            .unwrap_err();
+        assert_eq!(err, expected);
+    }
+
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(MINTER, &[]),
+            update_fee(vec![eu.clone(), us.clone(), mars.clone()]),
+        )
+        .unwrap();
+    let fee: Option<RedemptionFee> = from_binary(
+        &contract
+            .query(deps.as_ref(), mock_env(), QueryMsg::RedemptionFee {})
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        fee,
+        Some(RedemptionFee {
+            recipient: Addr::unchecked("treasury"),
+            regions: vec![eu, us, mars],
+        })
+    );
+
+    let redeem = |region: Option<&str>| ExecuteMsg::RedeemMedal {
+        token_id: "7".to_string(),
+        region: region.map(String::from),
+    };
+    let invalid_redemptions = vec![
+        (
+            redeem(None),
+            coins(5_000_000, "uusd"),
+            ContractError::MissingRegion {},
+        ),
+        (
+            redeem(Some("Venus")),
+            coins(5_000_000, "uusd"),
+            ContractError::UnknownRegion {
+                region: "Venus".to_string(),
+            },
+        ),
+        (
+            redeem(Some("Mars")),
+            coins(5_000_000, "uusd"),
+            ContractError::WrongAmountOfFundsSent {},
+        ),
+        (
+            redeem(Some("EU")),
+            vec![],
+            ContractError::WrongAmountOfFundsSent {},
+        ),
+        (
+            redeem(Some("EU")),
+            vec![coin(5_000_000, "uusd"), coin(100_000, "uluna")],
+            ContractError::WrongAmountOfFundsSent {},
+        ),
+        (
+            redeem(Some("US")),
+            coins(100_000, "uluna"),
+            ContractError::UnacceptedDenom {
+                region: "US".to_string(),
+                denom: "uluna".to_string(),
+            },
+        ),
+        (
+            redeem(Some("EU")),
+            coins(4_000_000, "uusd"),
+            ContractError::InsufficientFee {
+                denom: "uusd".to_string(),
+                required: Uint128::new(5_000_000),
+                sent: Uint128::new(4_000_000),
+            },
+        ),
+    ];
+    for (msg, funds, expected) in invalid_redemptions {
+        let err = contract
+            .execute(deps.as_mut(), mock_env(), mock_info("venus", &funds), msg)
+            .unwrap_err();
+        assert_eq!(err, expected);
+    }
+
+    // the fee is forwarded to the recipient and the overpayment refunded, both minus tax
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("venus", &coins(6_000_000, "uusd")),
+            redeem(Some("EU")),
+        )
+        .unwrap();
+    assert_eq!(
+        res.messages[1..]
+            .iter()
+            .map(|m| m.msg.clone())
+            .collect::<Vec<_>>(),
+        vec![
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: "treasury".to_string(),
+                amount: coins(4_995_004, "uusd"),
+            }),
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: "venus".to_string(),
+                amount: coins(999_000, "uusd"),
+            }),
+        ]
+    );
+    let redemption: Redemption = from_binary(
+        &contract
+            .query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::Redemption {
+                    token_id: "7".to_string(),
+                },
+            )
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        redemption,
+        Redemption {
+            redeemer: Addr::unchecked("venus"),
+            redeemed_token_id: "1".to_string(),
+            region: Some("EU".to_string()),
+            paid: Some(coin(5_000_000, "uusd")),
+        }
+    );
+
+    // once the fee is waived, no funds are accepted
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(MINTER, &[]),
+            update_fee(vec![]),
+        )
+        .unwrap();
+    let fee: Option<RedemptionFee> = from_binary(
+        &contract
+            .query(deps.as_ref(), mock_env(), QueryMsg::RedemptionFee {})
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(fee, None);
+
+    let redeem = ExecuteMsg::RedeemMedal {
+        token_id: "8".to_string(),
+        region: None,
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("venus", &coins(5_000_000, "uusd")),
+            redeem.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, ContractError::WrongAmountOfFundsSent {});
+    let res = contract
+        .execute(deps.as_mut(), mock_env(), mock_info("venus", &[]), redeem)
+        .unwrap();
+    assert_eq!(res.messages.len(), 1);
+    let redemption: Redemption = from_binary(
+        &contract
+            .query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::Redemption {
+                    token_id: "8".to_string(),
+                },
+            )
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(redemption.paid, None);
+}
+
+#[test]
+fn migrating() {
+    let mut deps = mock_dependencies(&[]);
+    let contract = setup_contract(deps.as_mut());
+
+    // contracts instantiated before pausing existed have no admin
+    contract.admin.remove(&mut deps.storage);
+    set_contract_version(&mut deps.storage, "crates.io:cw721-base", "0.1.0").unwrap();
+    let guardian_msg = ExecuteMsg::UpdateGuardian {
+        guardian: Some(String::from("lancelot")),
+    };
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ADMIN, &[]),
+            guardian_msg.clone(),
+        )
+        .unwrap_err();
+
+    let err = contract
+        .migrate(deps.as_mut(), mock_env(), MigrateMsg { admin: None })
+        .unwrap_err();
+    assert_eq!(err, ContractError::MissingAdmin {});
+    let msg = MigrateMsg {
+        admin: Some(String::from(ADMIN)),
+    };
+    let res = contract
+        .migrate(deps.as_mut(), mock_env(), msg.clone())
+        .unwrap();
+    assert_eq!("migrate", res.attributes[0].value);
+    assert_eq!("0.1.0", res.attributes[1].value);
+    assert_eq!(
+        get_contract_version(&deps.storage).unwrap().version,
+        env!("CARGO_PKG_VERSION")
+    );
+
+    // the stored admin can now set the guardian
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ADMIN, &[]),
+            guardian_msg,
+        )
+        .unwrap();
+
+    // cannot migrate to the same or an older version
+    for version in &[env!("CARGO_PKG_VERSION"), "99.0.0"] {
+        set_contract_version(&mut deps.storage, "crates.io:cw721-base", *version).unwrap();
+        let err = contract
+            .migrate(deps.as_mut(), mock_env(), msg.clone())
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::CannotDowngrade {
+                previous_version: version.to_string(),
+                new_version: env!("CARGO_PKG_VERSION").to_string(),
+            }
+        );
+    }
+
+    // the admin is kept by contracts that have one
+    set_contract_version(&mut deps.storage, "crates.io:cw721-base", "0.1.0").unwrap();
+    let res = contract
+        .migrate(
+            deps.as_mut(),
+            mock_env(),
+            MigrateMsg {
+                admin: Some(String::from("mallory")),
+            },
+        )
+        .unwrap();
+    assert_eq!(ADMIN, res.attributes[2].value);
+    assert_eq!(
+        Addr::unchecked(ADMIN),
+        contract.admin.load(&deps.storage).unwrap()
+    );
+
+    // cannot migrate from a different contract
+    set_contract_version(&mut deps.storage, "crates.io:other", "0.1.0").unwrap();
+    let err = contract
+        .migrate(deps.as_mut(), mock_env(), msg)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::ContractNameMismatch {
+            contract: String::from("crates.io:other")
+        }
+    );
+}
@@ -1,4 +1,5 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{StdError, Uint128};
+use mars_community::pause::PauseError;
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
@@ -6,6 +7,9 @@ pub enum ContractError {
     #[error("{0}")]
     Std(#[from] StdError),
 
+    #[error("{0}")]
+    Pause(#[from] PauseError),
+
     #[error("Unauthorized")]
     Unauthorized {},
 
@@ -15,6 +19,49 @@ pub enum ContractError {
     #[error("Cannot set approval that is already expired")]
     Expired {},
 
+    #[error("SVG template is {length} bytes long, the max is {max_length}")]
+    SvgTemplateTooLong { length: usize, max_length: usize },
+
     #[error("Wrong amount sent")]
     WrongAmountOfFundsSent {},
+
+    #[error("Redemption fee of {denom} must be positive")]
+    ZeroFeeAmount { denom: String },
+
+    #[error("Redemption fee of region {region} lists denom {denom} more than once")]
+    DuplicateFeeDenom { region: String, denom: String },
+
+    #[error("Redemption fee lists region {region} more than once")]
+    DuplicateRegion { region: String },
+
+    #[error("Shipping region is required to pay the redemption fee")]
+    MissingRegion {},
+
+    #[error("Redemption fee is not set for region {region}")]
+    UnknownRegion { region: String },
+
+    #[error("Denom {denom} is not accepted for the redemption fee of region {region}")]
+    UnacceptedDenom { region: String, denom: String },
+
+    #[error("Insufficient redemption fee: sent {sent}{denom}, required {required}{denom}")]
+    InsufficientFee {
+        denom: String,
+        required: Uint128,
+        sent: Uint128,
+    },
+
+    #[error("Invalid contract version: {version}")]
+    InvalidContractVersion { version: String },
+
+    #[error("Cannot migrate from a different contract: {contract}")]
+    ContractNameMismatch { contract: String },
+
+    #[error("Cannot migrate from version {previous_version} to version {new_version}, which is not newer")]
+    CannotDowngrade {
+        previous_version: String,
+        new_version: String,
+    },
+
+    #[error("Migrating a contract without an admin requires one")]
+    MissingAdmin {},
 }
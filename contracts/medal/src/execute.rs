@@ -2,16 +2,23 @@ use serde::de::DeserializeOwned;
 use serde::Serialize;
 
 use cosmwasm_std::{
-    to_binary, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Response, StdResult, WasmMsg,
+    to_binary, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Response,
+    StdResult, WasmMsg,
 };
 
-use cw2::set_contract_version;
+use cw2::{get_contract_version, set_contract_version};
 use cw721::{ContractInfoResponse, CustomMsg, Cw721Execute, Cw721ReceiveMsg, Expiration};
+use semver::Version;
 
 use crate::error::ContractError;
-use crate::msg::{ExecuteMsg, InstantiateMsg, MintMsg};
-use crate::state::{Approval, Cw721Contract, MedalMetaData, TokenInfo};
+use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, MintMsg};
+use crate::state::{Approval, Cw721Contract, MedalMetaData, Redemption, RedemptionFee, TokenInfo};
+use crate::svg::MAX_SVG_TEMPLATE_LENGTH;
+use mars_community::events::{self, MedalMintData, MedalRedeemData};
+use mars_community::manifesto::RegionFee;
 use mars_community::metadata::{Metadata, Trait};
+use mars_community::pause::{self, PauseFlag};
+use mars_community::tax::deduct_tax;
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:cw721-base";
@@ -38,9 +45,48 @@ where
         self.contract_info.save(deps.storage, &info)?;
         let minter = deps.api.addr_validate(&msg.minter)?;
         self.minter.save(deps.storage, &minter)?;
+        let admin = deps.api.addr_validate(&msg.admin)?;
+        self.admin.save(deps.storage, &admin)?;
         Ok(Response::default())
     }
 
+    pub fn migrate(
+        &self,
+        deps: DepsMut,
+        _env: Env,
+        msg: MigrateMsg,
+    ) -> Result<Response<C>, ContractError> {
+        let previous = get_contract_version(deps.storage)?;
+        if previous.contract != CONTRACT_NAME {
+            return Err(ContractError::ContractNameMismatch {
+                contract: previous.contract,
+            });
+        }
+        if parse_version(&previous.version)? >= parse_version(CONTRACT_VERSION)? {
+            return Err(ContractError::CannotDowngrade {
+                previous_version: previous.version,
+                new_version: CONTRACT_VERSION.to_string(),
+            });
+        }
+
+        // contracts instantiated before pausing existed have no admin, others keep theirs
+        let admin = match self.admin.may_load(deps.storage)? {
+            Some(admin) => admin,
+            None => {
+                let admin = msg.admin.ok_or(ContractError::MissingAdmin {})?;
+                let admin = deps.api.addr_validate(&admin)?;
+                self.admin.save(deps.storage, &admin)?;
+                admin
+            }
+        };
+        set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+        Ok(Response::new()
+            .add_attribute("action", "migrate")
+            .add_attribute("previous_version", previous.version)
+            .add_attribute("admin", admin))
+    }
+
     pub fn execute(
         &self,
         deps: DepsMut,
@@ -48,8 +94,19 @@ where
         info: MessageInfo,
         msg: ExecuteMsg<T>,
     ) -> Result<Response<C>, ContractError> {
+        match msg {
+            ExecuteMsg::Mint(_) => pause::assert_not_paused(deps.storage, PauseFlag::Minting)?,
+            ExecuteMsg::TransferNft { .. } | ExecuteMsg::SendNft { .. } => {
+                pause::assert_not_paused(deps.storage, PauseFlag::Transfers)?
+            }
+            _ => {}
+        }
+
         match msg {
             ExecuteMsg::Mint(msg) => self.mint(deps, env, info, msg),
+            ExecuteMsg::UpdateGuardian { guardian } => self.update_guardian(deps, info, guardian),
+            ExecuteMsg::Pause { flags } => self.pause(deps, info, flags),
+            ExecuteMsg::Unpause { flags } => self.unpause(deps, info, flags),
             ExecuteMsg::UpdateMedalRedeemConfig {
                 medal_redeem_addr,
                 metadata,
@@ -75,11 +132,81 @@ where
                 token_id,
                 msg,
             } => self.send_nft(deps, env, info, contract, token_id, msg),
-            ExecuteMsg::RedeemMedal { token_id } => self.redeem_medal(deps, env, info, token_id),
+            ExecuteMsg::RedeemMedal { token_id, region } => {
+                self.redeem_medal(deps, env, info, token_id, region)
+            }
+            ExecuteMsg::UpdateSvgTemplate { template } => {
+                self.update_svg_template(deps, info, template)
+            }
+            ExecuteMsg::UpdateRedemptionFee { recipient, regions } => {
+                self.update_redemption_fee(deps, info, recipient, regions)
+            }
+            ExecuteMsg::BurnMedal { token_id, owner } => {
+                self.burn_medal(deps, env, info, token_id, owner)
+            }
         }
     }
 }
 
+impl<'a, T, C> Cw721Contract<'a, T, C>
+where
+    T: Serialize + DeserializeOwned + Clone,
+    C: CustomMsg,
+{
+    pub fn update_guardian(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        guardian: Option<String>,
+    ) -> Result<Response<C>, ContractError> {
+        let admin = self.admin.load(deps.storage)?;
+        let guardian = guardian
+            .map(|guardian| deps.api.addr_validate(&guardian))
+            .transpose()?;
+
+        pause::update_guardian(deps.storage, &info.sender, &admin, guardian.clone())?;
+
+        Ok(Response::new()
+            .add_attribute("action", "update_guardian")
+            .add_attribute(
+                "guardian",
+                guardian
+                    .map(|guardian| guardian.to_string())
+                    .unwrap_or_default(),
+            ))
+    }
+
+    pub fn pause(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        flags: Vec<PauseFlag>,
+    ) -> Result<Response<C>, ContractError> {
+        let admin = self.admin.load(deps.storage)?;
+
+        pause::pause(deps.storage, &info.sender, &admin, &flags)?;
+
+        Ok(Response::new()
+            .add_attribute("action", "pause")
+            .add_attribute("flags", pause::flags_to_string(&flags)))
+    }
+
+    pub fn unpause(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        flags: Vec<PauseFlag>,
+    ) -> Result<Response<C>, ContractError> {
+        let admin = self.admin.load(deps.storage)?;
+
+        pause::unpause(deps.storage, &info.sender, &admin, &flags)?;
+
+        Ok(Response::new()
+            .add_attribute("action", "unpause")
+            .add_attribute("flags", pause::flags_to_string(&flags)))
+    }
+}
+
 // TODO pull this into some sort of trait extension??
 impl<'a, T, C> Cw721Contract<'a, T, C>
 where
@@ -110,6 +237,96 @@ where
             .add_attribute("action", "update_medal_redeem_config")
             .add_attribute("medal_redeem_addr", medal_redeem_addr))
     }
+
+    pub fn update_svg_template(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        template: Option<String>,
+    ) -> Result<Response<C>, ContractError> {
+        let minter = self.minter.load(deps.storage)?;
+
+        if info.sender != minter {
+            return Err(ContractError::Unauthorized {});
+        }
+
+        match template {
+            Some(template) => {
+                if template.len() > MAX_SVG_TEMPLATE_LENGTH {
+                    return Err(ContractError::SvgTemplateTooLong {
+                        length: template.len(),
+                        max_length: MAX_SVG_TEMPLATE_LENGTH,
+                    });
+                }
+                self.svg_template.save(deps.storage, &template)?;
+            }
+            None => self.svg_template.remove(deps.storage),
+        }
+
+        Ok(Response::new().add_attribute("action", "update_svg_template"))
+    }
+
+    pub fn update_redemption_fee(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        recipient: String,
+        regions: Vec<RegionFee>,
+    ) -> Result<Response<C>, ContractError> {
+        let minter = self.minter.load(deps.storage)?;
+
+        if info.sender != minter {
+            return Err(ContractError::Unauthorized {});
+        }
+
+        // Verify if regions are unique, amounts positive and denoms unique within a region
+        for (i, region_fee) in regions.iter().enumerate() {
+            if regions[..i].iter().any(|r| r.region == region_fee.region) {
+                return Err(ContractError::DuplicateRegion {
+                    region: region_fee.region.clone(),
+                });
+            }
+            let amounts = &region_fee.amounts;
+            for (j, coin) in amounts.iter().enumerate() {
+                if coin.amount.is_zero() {
+                    return Err(ContractError::ZeroFeeAmount {
+                        denom: coin.denom.clone(),
+                    });
+                }
+                if amounts[..j].iter().any(|c| c.denom == coin.denom) {
+                    return Err(ContractError::DuplicateFeeDenom {
+                        region: region_fee.region.clone(),
+                        denom: coin.denom.clone(),
+                    });
+                }
+            }
+        }
+
+        let recipient = deps.api.addr_validate(&recipient)?;
+        if regions.is_empty() {
+            self.redemption_fee.remove(deps.storage);
+        } else {
+            self.redemption_fee.save(
+                deps.storage,
+                &RedemptionFee {
+                    recipient: recipient.clone(),
+                    regions: regions.clone(),
+                },
+            )?;
+        }
+
+        Ok(Response::new()
+            .add_attribute("action", "update_redemption_fee")
+            .add_attribute("recipient", recipient)
+            .add_attribute(
+                "regions",
+                regions
+                    .iter()
+                    .map(|r| r.region.as_str())
+                    .collect::<Vec<_>>()
+                    .join(","),
+            ))
+    }
 }
 
 // TODO pull this into some sort of trait extension??
@@ -149,9 +366,17 @@ where
         self.increment_tokens(deps.storage)?;
 
         Ok(Response::new()
+            .add_event(events::medal_mint_event(
+                CONTRACT_VERSION,
+                &msg.token_id,
+                &msg.owner,
+            ))
             .add_attribute("action", "mint")
             .add_attribute("minter", info.sender)
-            .add_attribute("token_id", msg.token_id))
+            .add_attribute("token_id", msg.token_id.clone())
+            .set_data(to_binary(&MedalMintData {
+                token_id: msg.token_id,
+            })?))
     }
 }
 
@@ -167,13 +392,19 @@ where
         _env: Env,
         info: MessageInfo,
         token_id: String,
+        region: Option<String>,
     ) -> Result<Response<C>, ContractError> {
+        pause::assert_not_paused(deps.storage, PauseFlag::Redemption)?;
+
         let user_addr = info.sender.clone();
         let token = self.tokens.load(deps.storage, &token_id)?;
 
         // ensure we have permissions
         self.check_can_send(deps.as_ref(), &_env, &info, &token)?;
 
+        // Fee forwarded to the recipient and overpayment refunded to the redeemer
+        let (fee_msgs, paid) = self.collect_redemption_fee(deps.as_ref(), &info, &region)?;
+
         // MEDAL (Redeem) Address
         let medal_redeem_addr = self.get_medal_redeem_addr(deps.storage)?;
 
@@ -205,9 +436,7 @@ where
             external_url: None,
             description: Some(medal_redeem_metadata.description.clone()),
             name: Some(
-                medal_redeem_metadata.name_prefix.clone()
-                    + &" #".to_string()
-                    + &redeem_medal_id.to_string(),
+                medal_redeem_metadata.name_prefix.clone() + " #" + &redeem_medal_id.to_string(),
             ),
             attributes: Some(attributes_vec),
             background_color: None,
@@ -218,9 +447,7 @@ where
         let mint_msg = MintMsg {
             token_id: redeem_medal_id.to_string(),
             owner: user_addr.to_string(),
-            name: medal_redeem_metadata.name_prefix
-                + &" #".to_string()
-                + &redeem_medal_id.to_string(),
+            name: medal_redeem_metadata.name_prefix + " #" + &redeem_medal_id.to_string(),
             description: Some(medal_redeem_metadata.description),
             image: Some(medal_redeem_metadata.token_uri),
             extension: extension_,
@@ -240,12 +467,148 @@ where
         // Increment Redeemed Medals Count
         self.increment_redeemed_tokens(deps.storage)?;
 
+        self.redemptions.save(
+            deps.storage,
+            &token_id,
+            &Redemption {
+                redeemer: user_addr.clone(),
+                redeemed_token_id: redeem_medal_id.to_string(),
+                region: region.clone(),
+                paid: paid.clone(),
+            },
+        )?;
+
         Ok(Response::new()
             .add_message(mint_redeemed_medal_msg)
+            .add_messages(fee_msgs)
+            .add_event(events::medal_redeem_event(
+                CONTRACT_VERSION,
+                &token_id,
+                &user_addr,
+                &redeem_medal_id.to_string(),
+            ))
             .add_attribute("action", "redeem")
             .add_attribute("redeemer", user_addr)
+            .add_attribute("medal_id", token_id.clone())
+            .add_attribute("medal_redeemed_id", redeem_medal_id.to_string())
+            .add_attribute("region", region.unwrap_or_default())
+            .add_attribute(
+                "redemption_fee",
+                paid.map(|coin| coin.to_string()).unwrap_or_default(),
+            )
+            .set_data(to_binary(&MedalRedeemData {
+                token_id,
+                redeemed_token_id: redeem_medal_id.to_string(),
+            })?))
+    }
+}
+
+impl<'a, T, C> Cw721Contract<'a, T, C>
+where
+    T: Serialize + DeserializeOwned + Clone,
+    C: CustomMsg,
+{
+    /// Validates the funds sent to redeem a MEDAL against the fee of `region`. Returns the
+    /// messages forwarding the fee and refunding any overpayment, and the fee paid
+    fn collect_redemption_fee(
+        &self,
+        deps: Deps,
+        info: &MessageInfo,
+        region: &Option<String>,
+    ) -> Result<(Vec<BankMsg>, Option<Coin>), ContractError> {
+        let redemption_fee = match self.redemption_fee.may_load(deps.storage)? {
+            Some(redemption_fee) => redemption_fee,
+            None if info.funds.is_empty() => return Ok((vec![], None)),
+            None => return Err(ContractError::WrongAmountOfFundsSent {}),
+        };
+
+        let region = region.as_ref().ok_or(ContractError::MissingRegion {})?;
+        let region_fee = redemption_fee
+            .regions
+            .iter()
+            .find(|r| &r.region == region)
+            .ok_or_else(|| ContractError::UnknownRegion {
+                region: region.clone(),
+            })?;
+
+        let sent = match info.funds.as_slice() {
+            [] if region_fee.amounts.is_empty() => return Ok((vec![], None)),
+            [sent] if !region_fee.amounts.is_empty() => sent,
+            _ => return Err(ContractError::WrongAmountOfFundsSent {}),
+        };
+        let required = region_fee
+            .amounts
+            .iter()
+            .find(|coin| coin.denom == sent.denom)
+            .ok_or_else(|| ContractError::UnacceptedDenom {
+                region: region.clone(),
+                denom: sent.denom.clone(),
+            })?;
+        if sent.amount < required.amount {
+            return Err(ContractError::InsufficientFee {
+                denom: sent.denom.clone(),
+                required: required.amount,
+                sent: sent.amount,
+            });
+        }
+
+        let mut msgs = vec![BankMsg::Send {
+            to_address: redemption_fee.recipient.to_string(),
+            amount: vec![deduct_tax(&deps.querier, required.clone())?],
+        }];
+        let overpaid = sent.amount - required.amount;
+        if !overpaid.is_zero() {
+            msgs.push(BankMsg::Send {
+                to_address: info.sender.to_string(),
+                amount: vec![deduct_tax(
+                    &deps.querier,
+                    Coin {
+                        denom: sent.denom.clone(),
+                        amount: overpaid,
+                    },
+                )?],
+            });
+        }
+        Ok((msgs, Some(required.clone())))
+    }
+}
+
+// TODO pull this into some sort of trait extension??
+impl<'a, T, C> Cw721Contract<'a, T, C>
+where
+    T: Serialize + DeserializeOwned + Clone,
+    C: CustomMsg,
+{
+    pub fn burn_medal(
+        &self,
+        deps: DepsMut,
+        _env: Env,
+        info: MessageInfo,
+        token_id: String,
+        owner: String,
+    ) -> Result<Response<C>, ContractError> {
+        let minter = self.minter.load(deps.storage)?;
+
+        if info.sender != minter {
+            return Err(ContractError::Unauthorized {});
+        }
+
+        // The MEDAL may have been redeemed or transferred since it was minted
+        let owner_addr = deps.api.addr_validate(&owner)?;
+        let burned = match self.tokens.may_load(deps.storage, &token_id)? {
+            Some(token) if token.owner == owner_addr => {
+                self.tokens.remove(deps.storage, &token_id)?;
+                self.decrement_tokens(deps.storage)?;
+                true
+            }
+            _ => false,
+        };
+
+        Ok(Response::new()
+            .add_attribute("action", "burn_medal")
+            .add_attribute("owner", owner)
             .add_attribute("medal_id", token_id)
-            .add_attribute("medal_redeemed_id", redeem_medal_id.to_string()))
+            .add_attribute("burned", burned.to_string()))
     }
 }
 
@@ -264,9 +627,15 @@ where
         recipient: String,
         token_id: String,
     ) -> Result<Response<C>, ContractError> {
-        self._transfer_nft(deps, &env, &info, &recipient, &token_id)?;
+        let token = self._transfer_nft(deps, &env, &info, &recipient, &token_id)?;
 
         Ok(Response::new()
+            .add_event(events::medal_transfer_event(
+                CONTRACT_VERSION,
+                &token_id,
+                &info.sender,
+                &token.owner,
+            ))
             .add_attribute("action", "transfer_nft")
             .add_attribute("sender", info.sender)
             .add_attribute("recipient", recipient)
@@ -283,7 +652,7 @@ where
         msg: Binary,
     ) -> Result<Response<C>, ContractError> {
         // Transfer token
-        self._transfer_nft(deps, &env, &info, &contract, &token_id)?;
+        let token = self._transfer_nft(deps, &env, &info, &contract, &token_id)?;
 
         let send = Cw721ReceiveMsg {
             sender: info.sender.to_string(),
@@ -294,6 +663,12 @@ where
         // Send message
         Ok(Response::new()
             .add_message(send.into_cosmos_msg(contract.clone())?)
+            .add_event(events::medal_transfer_event(
+                CONTRACT_VERSION,
+                &token_id,
+                &info.sender,
+                &token.owner,
+            ))
             .add_attribute("action", "send_nft")
             .add_attribute("sender", info.sender)
             .add_attribute("recipient", contract)
@@ -420,11 +795,7 @@ where
 
         // update the approval list (remove any for the same spender before adding)
         let spender_addr = deps.api.addr_validate(spender)?;
-        token.approvals = token
-            .approvals
-            .into_iter()
-            .filter(|apr| apr.spender != spender_addr)
-            .collect();
+        token.approvals.retain(|apr| apr.spender != spender_addr);
 
         // only difference between approve and revoke
         if add {
@@ -511,3 +882,12 @@ where
         }
     }
 }
+
+/// Parses a contract version stored with cw2
+fn parse_version(version: &str) -> Result<Version, ContractError> {
+    version
+        .parse()
+        .map_err(|_| ContractError::InvalidContractVersion {
+            version: version.to_string(),
+        })
+}
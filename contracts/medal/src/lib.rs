@@ -4,9 +4,10 @@ mod execute;
 pub mod msg;
 mod query;
 pub mod state;
+pub mod svg;
 
 pub use crate::error::ContractError;
-pub use crate::msg::{ExecuteMsg, InstantiateMsg, MintMsg, MinterResponse, QueryMsg};
+pub use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, MintMsg, MinterResponse, QueryMsg};
 pub use crate::state::Cw721Contract;
 use cosmwasm_std::Empty;
 use mars_community::metadata::Metadata;
@@ -49,4 +50,10 @@ pub mod entry {
         let tract = Cw721Contract::<Extension, Empty>::default();
         tract.query(deps, env, msg)
     }
+
+    #[entry_point]
+    pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+        let tract = Cw721Contract::<Extension, Empty>::default();
+        tract.migrate(deps, env, msg)
+    }
 }
@@ -1,9 +1,7 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::state::MedalMetaData;
-use cosmwasm_std::Binary;
-use cw721::Expiration;
+pub use mars_community::medal::{ExecuteMsg, MintMsg};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct InstantiateMsg {
@@ -16,65 +14,16 @@ pub struct InstantiateMsg {
     /// This is designed for a base NFT that is controlled by an external program
     /// or contract. You will likely replace this with custom logic in custom NFTs
     pub minter: String,
-}
 
-/// This is like Cw721ExecuteMsg but we add a Mint command for an owner
-/// to make this stand-alone. You will likely want to remove mint and
-/// use other control logic in any contract that inherits this.
-#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug)]
-#[serde(rename_all = "snake_case")]
-pub enum ExecuteMsg<T> {
-    /// Admin function to set the MEDAL (Redeem) Contract address
-    UpdateMedalRedeemConfig {
-        medal_redeem_addr: String,
-        metadata: MedalMetaData,
-    },
-    /// Transfer is a base message to move a token to another account without triggering actions
-    TransferNft { recipient: String, token_id: String },
-    /// Send is a base message to transfer a token to a contract and trigger an action
-    /// on the receiving contract.
-    SendNft {
-        contract: String,
-        token_id: String,
-        msg: Binary,
-    },
-    /// Allows operator to transfer / send the token from the owner's account.
-    /// If expiration is set, then this allowance has a time/height limit
-    Approve {
-        spender: String,
-        token_id: String,
-        expires: Option<Expiration>,
-    },
-    /// Remove previously granted Approval
-    Revoke { spender: String, token_id: String },
-    /// Allows operator to transfer / send any token from the owner's account.
-    /// If expiration is set, then this allowance has a time/height limit
-    ApproveAll {
-        operator: String,
-        expires: Option<Expiration>,
-    },
-    /// Remove previously granted ApproveAll permission
-    RevokeAll { operator: String },
-    /// Mint a new NFT, can only be called by the contract minter
-    Mint(MintMsg<T>),
-    /// Mint a new NFT, can only be called by the contract minter
-    RedeemMedal { token_id: String },
+    /// The admin sets the pause guardian and is the only one who can lift pauses
+    pub admin: String,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
-pub struct MintMsg<T> {
-    /// Unique ID of the NFT
-    pub token_id: String,
-    /// The owner of the newly minter NFT
-    pub owner: String,
-    /// Identifies the asset to which this NFT represents
-    pub name: String,
-    /// Describes the asset to which this NFT represents (may be empty)
-    pub description: Option<String>,
-    /// A URI pointing to an image representing the asset
-    pub image: Option<String>,
-    /// Any custom extension used by this contract
-    pub extension: T,
+pub struct MigrateMsg {
+    /// The admin sets the pause guardian and is the only one who can lift pauses.
+    /// Only stored by contracts instantiated before pausing existed, which have none
+    pub admin: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -135,6 +84,19 @@ pub enum QueryMsg {
 
     // Return the minter
     Minter {},
+
+    /// Returns the guardian and the paused operations
+    /// Return type: PausedResponse
+    Paused {},
+
+    /// Returns the redemption fee, None if redemption is free
+    /// Return type: Option<RedemptionFee>
+    RedemptionFee {},
+    /// Returns the redemption of a MEDAL, error if it was not redeemed
+    /// Return type: Redemption
+    Redemption {
+        token_id: String,
+    },
 }
 
 /// Shows who can mint these tokens
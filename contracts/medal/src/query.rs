@@ -9,16 +9,18 @@ use cw721::{
     Expiration, NftInfoResponse, NumTokensResponse, OwnerOfResponse, TokensResponse,
 };
 use cw_storage_plus::Bound;
+use mars_community::pause;
 
 use crate::msg::{MinterResponse, QueryMsg};
 use crate::state::{Approval, Cw721Contract, TokenInfo};
+use crate::svg::{render_svg, SvgExtension};
 
 const DEFAULT_LIMIT: u32 = 10;
 const MAX_LIMIT: u32 = 30;
 
 impl<'a, T, C> Cw721Query<T> for Cw721Contract<'a, T, C>
 where
-    T: Serialize + DeserializeOwned + Clone,
+    T: Serialize + DeserializeOwned + Clone + SvgExtension,
     C: CustomMsg,
 {
     fn contract_info(&self, deps: Deps) -> StdResult<ContractInfoResponse> {
@@ -33,8 +35,8 @@ where
     fn nft_info(&self, deps: Deps, token_id: String) -> StdResult<NftInfoResponse<T>> {
         let info = self.tokens.load(deps.storage, &token_id)?;
         Ok(NftInfoResponse {
+            extension: self.render_image_data(deps, &token_id, &info)?,
             token_uri: info.image,
-            extension: info.extension,
         })
     }
 
@@ -137,8 +139,8 @@ where
                 approvals: humanize_approvals(&env.block, &info, include_expired),
             },
             info: NftInfoResponse {
+                extension: self.render_image_data(deps, &token_id, &info)?,
                 token_uri: info.image,
-                extension: info.extension,
             },
         })
     }
@@ -146,7 +148,7 @@ where
 
 impl<'a, T, C> Cw721Contract<'a, T, C>
 where
-    T: Serialize + DeserializeOwned + Clone,
+    T: Serialize + DeserializeOwned + Clone + SvgExtension,
     C: CustomMsg,
 {
     pub fn minter(&self, deps: Deps) -> StdResult<MinterResponse> {
@@ -156,9 +158,25 @@ where
         })
     }
 
+    /// Returns the extension of a token, with the SVG image rendered from the SVG template as
+    /// `image_data` if there is one
+    fn render_image_data(&self, deps: Deps, token_id: &str, info: &TokenInfo<T>) -> StdResult<T> {
+        let mut extension = info.extension.clone();
+        if let Some(template) = self.svg_template.may_load(deps.storage)? {
+            let svg = render_svg(&template, &info.name, token_id, &extension);
+            extension.set_image_data(svg);
+        }
+        Ok(extension)
+    }
+
     pub fn query(&self, deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         match msg {
             QueryMsg::Minter {} => to_binary(&self.minter(deps)?),
+            QueryMsg::Paused {} => to_binary(&pause::query_paused(deps.storage)?),
+            QueryMsg::RedemptionFee {} => to_binary(&self.redemption_fee.may_load(deps.storage)?),
+            QueryMsg::Redemption { token_id } => {
+                to_binary(&self.redemptions.load(deps.storage, &token_id)?)
+            }
             QueryMsg::ContractInfo {} => to_binary(&self.contract_info(deps)?),
             QueryMsg::NftInfo { token_id } => to_binary(&self.nft_info(deps, token_id)?),
             QueryMsg::OwnerOf {
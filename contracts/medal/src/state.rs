@@ -3,19 +3,31 @@ use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::marker::PhantomData;
 
-use cosmwasm_std::{Addr, BlockInfo, StdResult, Storage};
+use cosmwasm_std::{Addr, BlockInfo, Coin, StdResult, Storage};
 
 use cw721::{ContractInfoResponse, CustomMsg, Cw721, Expiration};
 use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex};
 
+use crate::svg::SvgExtension;
+pub use mars_community::manifesto::MedalMetaData;
+use mars_community::manifesto::RegionFee;
+
 pub struct Cw721Contract<'a, T, C>
 where
     T: Serialize + DeserializeOwned + Clone,
 {
     pub contract_info: Item<'a, ContractInfoResponse>,
     pub minter: Item<'a, Addr>,
+    /// Sets the pause guardian and lifts pauses
+    pub admin: Item<'a, Addr>,
     pub medal_redeem: Item<'a, Addr>,
     pub medal_redeem_info: Item<'a, MedalMetaData>,
+    /// SVG template the image of each token is rendered from, if any
+    pub svg_template: Item<'a, String>,
+    /// Fee charged to redeem a MEDAL, if any
+    pub redemption_fee: Item<'a, RedemptionFee>,
+    /// Redemptions, by id of the redeemed MEDAL
+    pub redemptions: Map<'a, &'a str, Redemption>,
     pub token_count: Item<'a, u64>,
     pub redeem_count: Item<'a, u64>,
     /// Stored as (granter, operator) giving operator full control over granter's account
@@ -27,7 +39,7 @@ where
 // This is a signal, the implementations are in other files
 impl<'a, T, C> Cw721<T, C> for Cw721Contract<'a, T, C>
 where
-    T: Serialize + DeserializeOwned + Clone,
+    T: Serialize + DeserializeOwned + Clone + SvgExtension,
     C: CustomMsg,
 {
 }
@@ -40,8 +52,12 @@ where
         Self::new(
             "nft_info",
             "minter",
+            "admin",
             "medal_redeem",
             "medal_redeem_info",
+            "svg_template",
+            "redemption_fee",
+            "redemptions",
             "num_tokens",
             "num_redeemed_tokens",
             "operators",
@@ -58,8 +74,12 @@ where
     fn new(
         contract_key: &'a str,
         minter_key: &'a str,
+        admin_key: &'a str,
         medal_redeem_key: &'a str,
         medal_redeem_info_key: &'a str,
+        svg_template_key: &'a str,
+        redemption_fee_key: &'a str,
+        redemptions_key: &'a str,
         token_count_key: &'a str,
         redeemed_token_count_key: &'a str,
         operator_key: &'a str,
@@ -72,8 +92,12 @@ where
         Self {
             contract_info: Item::new(contract_key),
             minter: Item::new(minter_key),
+            admin: Item::new(admin_key),
             medal_redeem: Item::new(medal_redeem_key),
             medal_redeem_info: Item::new(medal_redeem_info_key),
+            svg_template: Item::new(svg_template_key),
+            redemption_fee: Item::new(redemption_fee_key),
+            redemptions: Map::new(redemptions_key),
             token_count: Item::new(token_count_key),
             redeem_count: Item::new(redeemed_token_count_key),
             operators: Map::new(operator_key),
@@ -144,12 +168,23 @@ where
     }
 }
 
+/// Fee charged to redeem a MEDAL for a physical pin, which depends on the shipping region
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
-pub struct MedalMetaData {
-    pub name_prefix: String,
-    pub description: String,
-    pub image: String,
-    pub token_uri: String,
+pub struct RedemptionFee {
+    /// Address the fees are forwarded to
+    pub recipient: Addr,
+    pub regions: Vec<RegionFee>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Redemption {
+    pub redeemer: Addr,
+    /// Id of the R-MEDAL minted for the MEDAL
+    pub redeemed_token_id: String,
+    /// Region the pin is shipped to
+    pub region: Option<String>,
+    /// Fee paid, without the refunded overpayment. None if redemption was free
+    pub paid: Option<Coin>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -0,0 +1,62 @@
+use mars_community::manifesto::{MARTIAN_DATE_TRAIT, MARTIAN_TIME_TRAIT};
+use mars_community::metadata::Metadata;
+
+/// Max length (in bytes) of the SVG template
+pub const MAX_SVG_TEMPLATE_LENGTH: usize = 10_240;
+
+/// Token extension an SVG image can be rendered from
+pub trait SvgExtension {
+    /// Returns the value of a trait of the token, if it has it
+    fn trait_value(&self, trait_type: &str) -> Option<String>;
+
+    /// Sets the rendered SVG image
+    fn set_image_data(&mut self, image_data: String);
+}
+
+impl SvgExtension for Option<Metadata> {
+    fn trait_value(&self, trait_type: &str) -> Option<String> {
+        self.as_ref()?
+            .attributes
+            .as_ref()?
+            .iter()
+            .find(|trait_| trait_.trait_type == trait_type)
+            .map(|trait_| trait_.value.clone())
+    }
+
+    fn set_image_data(&mut self, image_data: String) {
+        self.get_or_insert_with(Metadata::default).image_data = Some(image_data);
+    }
+}
+
+/// Renders the SVG image of a token, replacing `{{name}}`, `{{ordinal}}`, `{{martian_date}}`
+/// and `{{martian_time}}` in the template. The ordinal is the token id, and the date and time are
+/// read from the traits the manifesto contract requires MEDAL templates to keep
+pub fn render_svg<T: SvgExtension>(
+    template: &str,
+    name: &str,
+    token_id: &str,
+    extension: &T,
+) -> String {
+    let trait_value = |trait_type| extension.trait_value(trait_type).unwrap_or_default();
+    template
+        .replace("{{name}}", &escape_xml(name))
+        .replace("{{ordinal}}", &escape_xml(token_id))
+        .replace(
+            "{{martian_date}}",
+            &escape_xml(&trait_value(MARTIAN_DATE_TRAIT)),
+        )
+        .replace(
+            "{{martian_time}}",
+            &escape_xml(&trait_value(MARTIAN_TIME_TRAIT)),
+        )
+}
+
+/// Escapes the characters of a value which would break the SVG markup
+fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
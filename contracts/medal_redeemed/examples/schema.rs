@@ -3,9 +3,13 @@ use std::fs::create_dir_all;
 
 use cosmwasm_schema::{export_schema, remove_schemas, schema_for};
 
-use medal_redeemed::msg::{ExecuteMsg, InstantiateMsg, MintMsg, MinterResponse, QueryMsg};
+use medal_redeemed::msg::{
+    ExecuteMsg, InstantiateMsg, MigrateMsg, MintMsg, MinterResponse, QueryMsg,
+};
 
+use mars_community::events::MedalMintData;
 use mars_community::metadata::Metadata;
+use mars_community::pause::PausedResponse;
 fn main() {
     let mut out_dir = current_dir().unwrap();
     out_dir.push("schema");
@@ -15,7 +19,10 @@ fn main() {
     export_schema(&schema_for!(InstantiateMsg), &out_dir);
     export_schema(&schema_for!(ExecuteMsg<Option<Metadata>>), &out_dir);
     export_schema(&schema_for!(QueryMsg), &out_dir);
+    export_schema(&schema_for!(MigrateMsg), &out_dir);
     export_schema(&schema_for!(MintMsg<Option<Metadata>>), &out_dir);
     export_schema(&schema_for!(MinterResponse), &out_dir);
+    export_schema(&schema_for!(PausedResponse), &out_dir);
+    export_schema(&schema_for!(MedalMintData), &out_dir);
     export_schema(&schema_for!(Metadata), &out_dir);
 }
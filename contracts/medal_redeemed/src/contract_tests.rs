@@ -1,17 +1,23 @@
 #![cfg(test)]
 use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
-use cosmwasm_std::{from_binary, to_binary, CosmosMsg, DepsMut, Empty, Response, WasmMsg};
+use cosmwasm_std::{from_binary, to_binary, Addr, CosmosMsg, DepsMut, Empty, Response, WasmMsg};
 
+use cw2::{get_contract_version, set_contract_version};
 use cw721::{
     ApprovedForAllResponse, ContractInfoResponse, Cw721Query, Cw721ReceiveMsg, Expiration,
     NftInfoResponse, OwnerOfResponse,
 };
 
+use mars_community::events::{self, MedalMintData};
+use mars_community::pause::{PauseError, PauseFlag, PauseFlags, PausedResponse};
+
 use crate::{
-    ContractError, Cw721Contract, ExecuteMsg, Extension, InstantiateMsg, MintMsg, QueryMsg,
+    ContractError, Cw721Contract, ExecuteMsg, Extension, InstantiateMsg, MigrateMsg, MintMsg,
+    QueryMsg,
 };
 
 const MINTER: &str = "merlin";
+const ADMIN: &str = "arthur";
 const CONTRACT_NAME: &str = "Magic Power";
 const SYMBOL: &str = "MGK";
 
@@ -21,6 +27,7 @@ fn setup_contract(deps: DepsMut<'_>) -> Cw721Contract<'static, Extension, Empty>
         name: CONTRACT_NAME.to_string(),
         symbol: SYMBOL.to_string(),
         minter: String::from(MINTER),
+        admin: String::from(ADMIN),
     };
     let info = mock_info("creator", &[]);
     let res = contract.instantiate(deps, mock_env(), info, msg).unwrap();
@@ -37,6 +44,7 @@ fn proper_instantiation() {
         name: CONTRACT_NAME.to_string(),
         symbol: SYMBOL.to_string(),
         minter: String::from(MINTER),
+        admin: String::from(ADMIN),
     };
     let info = mock_info("creator", &[]);
 
@@ -78,8 +86,8 @@ fn minting() {
     let mint_msg = ExecuteMsg::Mint(MintMsg::<Extension> {
         token_id: token_id.clone(),
         owner: String::from("medusa"),
-        name: name.clone(),
-        description: Some(description.clone()),
+        name,
+        description: Some(description),
         image: None,
         extension: None,
     });
@@ -93,9 +101,19 @@ fn minting() {
 
     // minter can mint
     let allowed = mock_info(MINTER, &[]);
-    let _ = contract
+    let res = contract
         .execute(deps.as_mut(), mock_env(), allowed, mint_msg)
         .unwrap();
+    assert_eq!(
+        res.events,
+        vec![events::medal_mint_event(
+            env!("CARGO_PKG_VERSION"),
+            &token_id,
+            "medusa"
+        )]
+    );
+    let data: MedalMintData = from_binary(&res.data.unwrap()).unwrap();
+    assert_eq!(data.token_id, token_id);
 
     // ensure num tokens increases
     let count = contract.num_tokens(deps.as_ref()).unwrap();
@@ -111,9 +129,7 @@ fn minting() {
     assert_eq!(
         info,
         NftInfoResponse::<Extension> {
-            name,
-            description,
-            image: None,
+            token_uri: None,
             extension: None,
         }
     );
@@ -202,6 +218,12 @@ fn transferring_nft() {
     assert_eq!(
         res,
         Response::new()
+            .add_event(events::medal_transfer_event(
+                env!("CARGO_PKG_VERSION"),
+                &token_id,
+                &Addr::unchecked("venus"),
+                &Addr::unchecked("random"),
+            ))
             .add_attribute("action", "transfer_nft")
             .add_attribute("sender", "venus")
             .add_attribute("recipient", "random")
@@ -271,6 +293,12 @@ fn sending_nft() {
         res,
         Response::new()
             .add_message(expected)
+            .add_event(events::medal_transfer_event(
+                env!("CARGO_PKG_VERSION"),
+                &token_id,
+                &Addr::unchecked("venus"),
+                &Addr::unchecked("another_contract"),
+            ))
             .add_attribute("action", "send_nft")
             .add_attribute("sender", "venus")
             .add_attribute("recipient", "another_contract")
@@ -613,9 +641,9 @@ fn query_tokens_by_owner() {
 
     // Mint a couple tokens (from the same owner)
     let token_id1 = "grow1".to_string();
-    let demeter = String::from("Demeter");
+    let demeter = String::from("demeter");
     let token_id2 = "grow2".to_string();
-    let ceres = String::from("Ceres");
+    let ceres = String::from("ceres");
     let token_id3 = "sing".to_string();
 
     let mint_msg = ExecuteMsg::Mint(MintMsg::<Extension> {
@@ -687,3 +715,256 @@ fn query_tokens_by_owner() {
         .unwrap();
     assert_eq!(&by_demeter[1..], &tokens.tokens[..]);
 }
+
+#[test]
+fn pausing() {
+    let mut deps = mock_dependencies(&[]);
+    let contract = setup_contract(deps.as_mut());
+
+    let mint_msg = |token_id: &str| {
+        ExecuteMsg::Mint(MintMsg::<Extension> {
+            token_id: token_id.to_string(),
+            owner: String::from("venus"),
+            name: "MEDAL #".to_string() + token_id,
+            description: None,
+            image: None,
+            extension: None,
+        })
+    };
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(MINTER, &[]),
+            mint_msg("1"),
+        )
+        .unwrap();
+
+    // only the admin can set the guardian
+    let guardian_msg = ExecuteMsg::UpdateGuardian {
+        guardian: Some(String::from("lancelot")),
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(MINTER, &[]),
+            guardian_msg.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, ContractError::Pause(PauseError::Unauthorized {}));
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ADMIN, &[]),
+            guardian_msg,
+        )
+        .unwrap();
+
+    // random cannot pause, the guardian can
+    let pause_msg = ExecuteMsg::Pause {
+        flags: vec![PauseFlag::Minting, PauseFlag::Transfers],
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("random", &[]),
+            pause_msg.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, ContractError::Pause(PauseError::Unauthorized {}));
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("lancelot", &[]),
+            pause_msg,
+        )
+        .unwrap();
+    assert_eq!("pause", res.attributes[0].value);
+
+    let res: PausedResponse = from_binary(
+        &contract
+            .query(deps.as_ref(), mock_env(), QueryMsg::Paused {})
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        res,
+        PausedResponse {
+            guardian: Some(Addr::unchecked("lancelot")),
+            paused: PauseFlags {
+                signing: false,
+                minting: true,
+                transfers: true,
+                redemption: false,
+            },
+        }
+    );
+
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(MINTER, &[]),
+            mint_msg("2"),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Pause(PauseError::Paused {
+            flag: PauseFlag::Minting
+        })
+    );
+
+    let transfer_msg = ExecuteMsg::TransferNft {
+        recipient: String::from("random"),
+        token_id: "1".to_string(),
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("venus", &[]),
+            transfer_msg.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Pause(PauseError::Paused {
+            flag: PauseFlag::Transfers
+        })
+    );
+    // the guardian cannot unpause, the admin can
+    let unpause_msg = ExecuteMsg::Unpause {
+        flags: vec![PauseFlag::Transfers],
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("lancelot", &[]),
+            unpause_msg.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, ContractError::Pause(PauseError::Unauthorized {}));
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ADMIN, &[]),
+            unpause_msg,
+        )
+        .unwrap();
+
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("venus", &[]),
+            transfer_msg,
+        )
+        .unwrap();
+    let _ = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(MINTER, &[]),
+            mint_msg("2"),
+        )
+        .unwrap_err();
+}
+
+#[test]
+fn migrating() {
+    let mut deps = mock_dependencies(&[]);
+    let contract = setup_contract(deps.as_mut());
+
+    // contracts instantiated before pausing existed have no admin
+    contract.admin.remove(&mut deps.storage);
+    set_contract_version(&mut deps.storage, "crates.io:cw721-base", "0.1.0").unwrap();
+    let guardian_msg = ExecuteMsg::UpdateGuardian {
+        guardian: Some(String::from("lancelot")),
+    };
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ADMIN, &[]),
+            guardian_msg.clone(),
+        )
+        .unwrap_err();
+
+    let err = contract
+        .migrate(deps.as_mut(), mock_env(), MigrateMsg { admin: None })
+        .unwrap_err();
+    assert_eq!(err, ContractError::MissingAdmin {});
+    let msg = MigrateMsg {
+        admin: Some(String::from(ADMIN)),
+    };
+    let res = contract
+        .migrate(deps.as_mut(), mock_env(), msg.clone())
+        .unwrap();
+    assert_eq!("migrate", res.attributes[0].value);
+    assert_eq!("0.1.0", res.attributes[1].value);
+    assert_eq!(
+        get_contract_version(&deps.storage).unwrap().version,
+        env!("CARGO_PKG_VERSION")
+    );
+
+    // the stored admin can now set the guardian
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ADMIN, &[]),
+            guardian_msg,
+        )
+        .unwrap();
+
+    // cannot migrate to the same or an older version
+    for version in &[env!("CARGO_PKG_VERSION"), "99.0.0"] {
+        set_contract_version(&mut deps.storage, "crates.io:cw721-base", *version).unwrap();
+        let err = contract
+            .migrate(deps.as_mut(), mock_env(), msg.clone())
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::CannotDowngrade {
+                previous_version: version.to_string(),
+                new_version: env!("CARGO_PKG_VERSION").to_string(),
+            }
+        );
+    }
+
+    // the admin is kept by contracts that have one
+    set_contract_version(&mut deps.storage, "crates.io:cw721-base", "0.1.0").unwrap();
+    let res = contract
+        .migrate(
+            deps.as_mut(),
+            mock_env(),
+            MigrateMsg {
+                admin: Some(String::from("mallory")),
+            },
+        )
+        .unwrap();
+    assert_eq!(ADMIN, res.attributes[2].value);
+    assert_eq!(
+        Addr::unchecked(ADMIN),
+        contract.admin.load(&deps.storage).unwrap()
+    );
+
+    // cannot migrate from a different contract
+    set_contract_version(&mut deps.storage, "crates.io:other", "0.1.0").unwrap();
+    let err = contract
+        .migrate(deps.as_mut(), mock_env(), msg)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::ContractNameMismatch {
+            contract: String::from("crates.io:other")
+        }
+    );
+}
@@ -1,4 +1,5 @@
 use cosmwasm_std::StdError;
+use mars_community::pause::PauseError;
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
@@ -6,6 +7,9 @@ pub enum ContractError {
     #[error("{0}")]
     Std(#[from] StdError),
 
+    #[error("{0}")]
+    Pause(#[from] PauseError),
+
     #[error("Unauthorized")]
     Unauthorized {},
 
@@ -17,4 +21,19 @@ pub enum ContractError {
 
     #[error("Wrong amount sent")]
     WrongAmountOfFundsSent {},
+
+    #[error("Invalid contract version: {version}")]
+    InvalidContractVersion { version: String },
+
+    #[error("Cannot migrate from a different contract: {contract}")]
+    ContractNameMismatch { contract: String },
+
+    #[error("Cannot migrate from version {previous_version} to version {new_version}, which is not newer")]
+    CannotDowngrade {
+        previous_version: String,
+        new_version: String,
+    },
+
+    #[error("Migrating a contract without an admin requires one")]
+    MissingAdmin {},
 }
@@ -1,14 +1,17 @@
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 
-use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
+use cosmwasm_std::{to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
 
-use cw2::set_contract_version;
+use cw2::{get_contract_version, set_contract_version};
 use cw721::{ContractInfoResponse, CustomMsg, Cw721Execute, Cw721ReceiveMsg, Expiration};
+use semver::Version;
 
 use crate::error::ContractError;
-use crate::msg::{ExecuteMsg, InstantiateMsg, MintMsg};
+use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, MintMsg};
 use crate::state::{Approval, Cw721Contract, TokenInfo};
+use mars_community::events::{self, MedalMintData};
+use mars_community::pause::{self, PauseFlag};
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:cw721-base";
@@ -35,9 +38,48 @@ where
         self.contract_info.save(deps.storage, &info)?;
         let minter = deps.api.addr_validate(&msg.minter)?;
         self.minter.save(deps.storage, &minter)?;
+        let admin = deps.api.addr_validate(&msg.admin)?;
+        self.admin.save(deps.storage, &admin)?;
         Ok(Response::default())
     }
 
+    pub fn migrate(
+        &self,
+        deps: DepsMut,
+        _env: Env,
+        msg: MigrateMsg,
+    ) -> Result<Response<C>, ContractError> {
+        let previous = get_contract_version(deps.storage)?;
+        if previous.contract != CONTRACT_NAME {
+            return Err(ContractError::ContractNameMismatch {
+                contract: previous.contract,
+            });
+        }
+        if parse_version(&previous.version)? >= parse_version(CONTRACT_VERSION)? {
+            return Err(ContractError::CannotDowngrade {
+                previous_version: previous.version,
+                new_version: CONTRACT_VERSION.to_string(),
+            });
+        }
+
+        // contracts instantiated before pausing existed have no admin, others keep theirs
+        let admin = match self.admin.may_load(deps.storage)? {
+            Some(admin) => admin,
+            None => {
+                let admin = msg.admin.ok_or(ContractError::MissingAdmin {})?;
+                let admin = deps.api.addr_validate(&admin)?;
+                self.admin.save(deps.storage, &admin)?;
+                admin
+            }
+        };
+        set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+        Ok(Response::new()
+            .add_attribute("action", "migrate")
+            .add_attribute("previous_version", previous.version)
+            .add_attribute("admin", admin))
+    }
+
     pub fn execute(
         &self,
         deps: DepsMut,
@@ -45,8 +87,19 @@ where
         info: MessageInfo,
         msg: ExecuteMsg<T>,
     ) -> Result<Response<C>, ContractError> {
+        match msg {
+            ExecuteMsg::Mint(_) => pause::assert_not_paused(deps.storage, PauseFlag::Minting)?,
+            ExecuteMsg::TransferNft { .. } | ExecuteMsg::SendNft { .. } => {
+                pause::assert_not_paused(deps.storage, PauseFlag::Transfers)?
+            }
+            _ => {}
+        }
+
         match msg {
             ExecuteMsg::Mint(msg) => self.mint(deps, env, info, msg),
+            ExecuteMsg::UpdateGuardian { guardian } => self.update_guardian(deps, info, guardian),
+            ExecuteMsg::Pause { flags } => self.pause(deps, info, flags),
+            ExecuteMsg::Unpause { flags } => self.unpause(deps, info, flags),
             ExecuteMsg::Approve {
                 spender,
                 token_id,
@@ -72,6 +125,65 @@ where
     }
 }
 
+impl<'a, T, C> Cw721Contract<'a, T, C>
+where
+    T: Serialize + DeserializeOwned + Clone,
+    C: CustomMsg,
+{
+    pub fn update_guardian(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        guardian: Option<String>,
+    ) -> Result<Response<C>, ContractError> {
+        let admin = self.admin.load(deps.storage)?;
+        let guardian = guardian
+            .map(|guardian| deps.api.addr_validate(&guardian))
+            .transpose()?;
+
+        pause::update_guardian(deps.storage, &info.sender, &admin, guardian.clone())?;
+
+        Ok(Response::new()
+            .add_attribute("action", "update_guardian")
+            .add_attribute(
+                "guardian",
+                guardian
+                    .map(|guardian| guardian.to_string())
+                    .unwrap_or_default(),
+            ))
+    }
+
+    pub fn pause(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        flags: Vec<PauseFlag>,
+    ) -> Result<Response<C>, ContractError> {
+        let admin = self.admin.load(deps.storage)?;
+
+        pause::pause(deps.storage, &info.sender, &admin, &flags)?;
+
+        Ok(Response::new()
+            .add_attribute("action", "pause")
+            .add_attribute("flags", pause::flags_to_string(&flags)))
+    }
+
+    pub fn unpause(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        flags: Vec<PauseFlag>,
+    ) -> Result<Response<C>, ContractError> {
+        let admin = self.admin.load(deps.storage)?;
+
+        pause::unpause(deps.storage, &info.sender, &admin, &flags)?;
+
+        Ok(Response::new()
+            .add_attribute("action", "unpause")
+            .add_attribute("flags", pause::flags_to_string(&flags)))
+    }
+}
+
 // TODO pull this into some sort of trait extension??
 impl<'a, T, C> Cw721Contract<'a, T, C>
 where
@@ -109,9 +221,17 @@ where
         self.increment_tokens(deps.storage)?;
 
         Ok(Response::new()
+            .add_event(events::medal_mint_event(
+                CONTRACT_VERSION,
+                &msg.token_id,
+                &msg.owner,
+            ))
             .add_attribute("action", "mint")
             .add_attribute("minter", info.sender)
-            .add_attribute("token_id", msg.token_id))
+            .add_attribute("token_id", msg.token_id.clone())
+            .set_data(to_binary(&MedalMintData {
+                token_id: msg.token_id,
+            })?))
     }
 }
 
@@ -130,9 +250,15 @@ where
         recipient: String,
         token_id: String,
     ) -> Result<Response<C>, ContractError> {
-        self._transfer_nft(deps, &env, &info, &recipient, &token_id)?;
+        let token = self._transfer_nft(deps, &env, &info, &recipient, &token_id)?;
 
         Ok(Response::new()
+            .add_event(events::medal_transfer_event(
+                CONTRACT_VERSION,
+                &token_id,
+                &info.sender,
+                &token.owner,
+            ))
             .add_attribute("action", "transfer_nft")
             .add_attribute("sender", info.sender)
             .add_attribute("recipient", recipient)
@@ -149,7 +275,7 @@ where
         msg: Binary,
     ) -> Result<Response<C>, ContractError> {
         // Transfer token
-        self._transfer_nft(deps, &env, &info, &contract, &token_id)?;
+        let token = self._transfer_nft(deps, &env, &info, &contract, &token_id)?;
 
         let send = Cw721ReceiveMsg {
             sender: info.sender.to_string(),
@@ -160,6 +286,12 @@ where
         // Send message
         Ok(Response::new()
             .add_message(send.into_cosmos_msg(contract.clone())?)
+            .add_event(events::medal_transfer_event(
+                CONTRACT_VERSION,
+                &token_id,
+                &info.sender,
+                &token.owner,
+            ))
             .add_attribute("action", "send_nft")
             .add_attribute("sender", info.sender)
             .add_attribute("recipient", contract)
@@ -286,11 +418,7 @@ where
 
         // update the approval list (remove any for the same spender before adding)
         let spender_addr = deps.api.addr_validate(spender)?;
-        token.approvals = token
-            .approvals
-            .into_iter()
-            .filter(|apr| apr.spender != spender_addr)
-            .collect();
+        token.approvals.retain(|apr| apr.spender != spender_addr);
 
         // only difference between approve and revoke
         if add {
@@ -377,3 +505,12 @@ where
         }
     }
 }
+
+/// Parses a contract version stored with cw2
+fn parse_version(version: &str) -> Result<Version, ContractError> {
+    version
+        .parse()
+        .map_err(|_| ContractError::InvalidContractVersion {
+            version: version.to_string(),
+        })
+}
@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 
 use cosmwasm_std::Binary;
 use cw721::Expiration;
+use mars_community::pause::PauseFlag;
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct InstantiateMsg {
@@ -15,6 +16,16 @@ pub struct InstantiateMsg {
     /// This is designed for a base NFT that is controlled by an external program
     /// or contract. You will likely replace this with custom logic in custom NFTs
     pub minter: String,
+
+    /// The admin sets the pause guardian and is the only one who can lift pauses
+    pub admin: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MigrateMsg {
+    /// The admin sets the pause guardian and is the only one who can lift pauses.
+    /// Only stored by contracts instantiated before pausing existed, which have none
+    pub admin: Option<String>,
 }
 
 /// This is like Cw721ExecuteMsg but we add a Mint command for an owner
@@ -51,6 +62,13 @@ pub enum ExecuteMsg<T> {
     RevokeAll { operator: String },
     /// Mint a new NFT, can only be called by the contract minter
     Mint(MintMsg<T>),
+    /// Sets (or removes, if None) the guardian, who may pause operations but not unpause them.
+    /// Can only be called by the admin
+    UpdateGuardian { guardian: Option<String> },
+    /// Pauses operations, can be called by the guardian or the admin
+    Pause { flags: Vec<PauseFlag> },
+    /// Lifts the pause of operations, can only be called by the admin
+    Unpause { flags: Vec<PauseFlag> },
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -127,6 +145,10 @@ pub enum QueryMsg {
 
     // Return the minter
     Minter {},
+
+    /// Returns the guardian and the paused operations
+    /// Return type: PausedResponse
+    Paused {},
 }
 
 /// Shows who can mint these tokens
@@ -14,6 +14,8 @@ where
 {
     pub contract_info: Item<'a, ContractInfoResponse>,
     pub minter: Item<'a, Addr>,
+    /// Sets the pause guardian and lifts pauses
+    pub admin: Item<'a, Addr>,
     pub token_count: Item<'a, u64>,
     /// Stored as (granter, operator) giving operator full control over granter's account
     pub operators: Map<'a, (&'a Addr, &'a Addr), Expiration>,
@@ -38,6 +40,7 @@ where
         Self::new(
             "nft_info",
             "minter",
+            "admin",
             "num_tokens",
             "operators",
             "tokens",
@@ -53,6 +56,7 @@ where
     fn new(
         contract_key: &'a str,
         minter_key: &'a str,
+        admin_key: &'a str,
         token_count_key: &'a str,
         operator_key: &'a str,
         tokens_key: &'a str,
@@ -64,6 +68,7 @@ where
         Self {
             contract_info: Item::new(contract_key),
             minter: Item::new(minter_key),
+            admin: Item::new(admin_key),
             token_count: Item::new(token_count_key),
             operators: Map::new(operator_key),
             tokens: IndexedMap::new(tokens_key, indexes),
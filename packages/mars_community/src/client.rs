@@ -0,0 +1,637 @@
+use cosmwasm_std::{
+    to_binary, Addr, Binary, Coin, CosmosMsg, QuerierWrapper, StdResult, WasmMsg, WasmQuery,
+};
+use cw20::Expiration;
+use cw721::{Cw721QueryMsg, NftInfoResponse, NumTokensResponse, OwnerOfResponse, TokensResponse};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::manifesto::{
+    ConfigResponse, ExecuteMsg, ImportedSignature, LegacyMedal, ManifestoResponse, MedalExecuteMsg,
+    MedalMetaData, MedalTemplate, MedalTier, MintMsg, QueryMsg, RegionFee, SignatureResponse,
+    SigneeResponse, SigneesResponse, SigningPhase, StateResponse,
+};
+use crate::metadata::Metadata;
+use crate::pause::PauseFlag;
+
+/// Max number of token ids listed per MEDAL query, see `MedalContract::medal_count`
+const MEDAL_PAGE_LIMIT: u32 = 30;
+
+/// Manifesto contract, queried and called by other contracts
+#[derive(Clone, Debug, PartialEq)]
+pub struct ManifestoContract(pub Addr);
+
+impl ManifestoContract {
+    pub fn addr(&self) -> Addr {
+        self.0.clone()
+    }
+
+    /// Builds a message executing any `ExecuteMsg` on the manifesto contract
+    pub fn call(&self, msg: ExecuteMsg, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+        execute_msg(&self.0, &msg, funds)
+    }
+
+    /// Proposes a new admin, who has to claim the role with `claim_admin` before `expires`
+    pub fn propose_new_admin(
+        &self,
+        new_admin: String,
+        expires: Expiration,
+    ) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::ProposeNewAdmin { new_admin, expires }, vec![])
+    }
+
+    pub fn claim_admin(&self) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::ClaimAdmin {}, vec![])
+    }
+
+    pub fn drop_admin_proposal(&self) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::DropAdminProposal {}, vec![])
+    }
+
+    pub fn register_manifesto(
+        &self,
+        title: String,
+        content_hash: String,
+        uri: Option<String>,
+        max_signees_limit: u64,
+        medal_addr: Option<String>,
+        medal_redeem_addr: Option<String>,
+    ) -> StdResult<CosmosMsg> {
+        self.call(
+            ExecuteMsg::RegisterManifesto {
+                title,
+                content_hash,
+                uri,
+                max_signees_limit,
+                medal_addr,
+                medal_redeem_addr,
+            },
+            vec![],
+        )
+    }
+
+    pub fn update_manifesto(
+        &self,
+        manifesto_id: u64,
+        title: Option<String>,
+        max_signees_limit: Option<u64>,
+    ) -> StdResult<CosmosMsg> {
+        self.call(
+            ExecuteMsg::UpdateManifesto {
+                manifesto_id,
+                title,
+                max_signees_limit,
+            },
+            vec![],
+        )
+    }
+
+    pub fn amend_manifesto(
+        &self,
+        manifesto_id: u64,
+        content_hash: String,
+        uri: Option<String>,
+    ) -> StdResult<CosmosMsg> {
+        self.call(
+            ExecuteMsg::AmendManifesto {
+                manifesto_id,
+                content_hash,
+                uri,
+            },
+            vec![],
+        )
+    }
+
+    pub fn update_medal_config(
+        &self,
+        manifesto_id: u64,
+        medal_addr: String,
+        metadata: Metadata,
+    ) -> StdResult<CosmosMsg> {
+        self.call(
+            ExecuteMsg::UpdateMedalConfig {
+                manifesto_id,
+                medal_addr,
+                metadata,
+            },
+            vec![],
+        )
+    }
+
+    pub fn update_medal_redeem_config(
+        &self,
+        manifesto_id: u64,
+        medal_redeem_addr: String,
+        metadata: MedalMetaData,
+    ) -> StdResult<CosmosMsg> {
+        self.call(
+            ExecuteMsg::UpdateMedalRedeemConfig {
+                manifesto_id,
+                medal_redeem_addr,
+                metadata,
+            },
+            vec![],
+        )
+    }
+
+    pub fn update_medal_template(
+        &self,
+        manifesto_id: u64,
+        template: Option<MedalTemplate>,
+    ) -> StdResult<CosmosMsg> {
+        self.call(
+            ExecuteMsg::UpdateMedalTemplate {
+                manifesto_id,
+                template,
+            },
+            vec![],
+        )
+    }
+
+    pub fn update_medal_svg_template(
+        &self,
+        manifesto_id: u64,
+        template: Option<String>,
+    ) -> StdResult<CosmosMsg> {
+        self.call(
+            ExecuteMsg::UpdateMedalSvgTemplate {
+                manifesto_id,
+                template,
+            },
+            vec![],
+        )
+    }
+
+    pub fn update_medal_redemption_fee(
+        &self,
+        manifesto_id: u64,
+        recipient: String,
+        regions: Vec<RegionFee>,
+    ) -> StdResult<CosmosMsg> {
+        self.call(
+            ExecuteMsg::UpdateMedalRedemptionFee {
+                manifesto_id,
+                recipient,
+                regions,
+            },
+            vec![],
+        )
+    }
+
+    pub fn update_medal_tiers(
+        &self,
+        manifesto_id: u64,
+        tiers: Vec<MedalTier>,
+    ) -> StdResult<CosmosMsg> {
+        self.call(
+            ExecuteMsg::UpdateMedalTiers {
+                manifesto_id,
+                tiers,
+            },
+            vec![],
+        )
+    }
+
+    pub fn update_time_tolerance(&self, time_tolerance: u64) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::UpdateTimeTolerance { time_tolerance }, vec![])
+    }
+
+    pub fn update_signing_phases(
+        &self,
+        manifesto_id: u64,
+        phases: Vec<SigningPhase>,
+    ) -> StdResult<CosmosMsg> {
+        self.call(
+            ExecuteMsg::UpdateSigningPhases {
+                manifesto_id,
+                phases,
+            },
+            vec![],
+        )
+    }
+
+    pub fn update_merkle_root(
+        &self,
+        manifesto_id: u64,
+        round: u32,
+        merkle_root: Option<String>,
+    ) -> StdResult<CosmosMsg> {
+        self.call(
+            ExecuteMsg::UpdateMerkleRoot {
+                manifesto_id,
+                round,
+                merkle_root,
+            },
+            vec![],
+        )
+    }
+
+    pub fn update_signing_fee(&self, treasury: String, amounts: Vec<Coin>) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::UpdateSigningFee { treasury, amounts }, vec![])
+    }
+
+    pub fn update_guardian(&self, guardian: Option<String>) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::UpdateGuardian { guardian }, vec![])
+    }
+
+    pub fn pause(&self, flags: Vec<PauseFlag>) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::Pause { flags }, vec![])
+    }
+
+    pub fn unpause(&self, flags: Vec<PauseFlag>) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::Unpause { flags }, vec![])
+    }
+
+    /// Signs a manifesto on behalf of the contract. `funds` pay the signing fee, if any
+    pub fn sign_manifesto(
+        &self,
+        manifesto_id: u64,
+        content_hash: String,
+        proof: Option<Vec<String>>,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg> {
+        self.call(
+            ExecuteMsg::SignManifesto {
+                manifesto_id,
+                content_hash,
+                martian_date: None,
+                martian_time: None,
+                proof,
+            },
+            funds,
+        )
+    }
+
+    /// Relays a signature the owner of `signee_pubkey` made off-chain over a
+    /// `SignManifestoPayload`. `funds` pay the signing fee, if any
+    pub fn sign_manifesto_for(
+        &self,
+        manifesto_id: u64,
+        content_hash: String,
+        signee_pubkey: Binary,
+        signature: Binary,
+        martian_date: Option<String>,
+        martian_time: Option<String>,
+        nonce: u64,
+        proof: Option<Vec<String>>,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg> {
+        self.call(
+            ExecuteMsg::SignManifestoFor {
+                manifesto_id,
+                content_hash,
+                signee_pubkey,
+                signature,
+                martian_date,
+                martian_time,
+                nonce,
+                proof,
+            },
+            funds,
+        )
+    }
+
+    pub fn withdraw_signature(&self, manifesto_id: u64) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::WithdrawSignature { manifesto_id }, vec![])
+    }
+
+    pub fn retry_medal_mint(&self, manifesto_id: u64) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::RetryMedalMint { manifesto_id }, vec![])
+    }
+
+    pub fn import_signatures(
+        &self,
+        manifesto_id: u64,
+        entries: Vec<ImportedSignature>,
+        mint_medals: bool,
+    ) -> StdResult<CosmosMsg> {
+        self.call(
+            ExecuteMsg::ImportSignatures {
+                manifesto_id,
+                entries,
+                mint_medals,
+            },
+            vec![],
+        )
+    }
+
+    pub fn finalize_import(&self) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::FinalizeImport {}, vec![])
+    }
+
+    pub fn migrate_legacy_signatures(&self, entries: Vec<LegacyMedal>) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::MigrateLegacySignatures { entries }, vec![])
+    }
+
+    pub fn config(&self, querier: &QuerierWrapper) -> StdResult<ConfigResponse> {
+        smart_query(querier, &self.0, &QueryMsg::Config {})
+    }
+
+    pub fn manifesto(
+        &self,
+        querier: &QuerierWrapper,
+        manifesto_id: u64,
+    ) -> StdResult<ManifestoResponse> {
+        smart_query(querier, &self.0, &QueryMsg::Manifesto { manifesto_id })
+    }
+
+    pub fn state(&self, querier: &QuerierWrapper, manifesto_id: u64) -> StdResult<StateResponse> {
+        smart_query(querier, &self.0, &QueryMsg::State { manifesto_id })
+    }
+
+    /// Returns true if `address` has an active signature
+    pub fn is_signee(
+        &self,
+        querier: &QuerierWrapper,
+        manifesto_id: u64,
+        address: impl Into<String>,
+    ) -> StdResult<bool> {
+        let res: SigneeResponse = smart_query(
+            querier,
+            &self.0,
+            &QueryMsg::IsSignee {
+                manifesto_id,
+                address: address.into(),
+            },
+        )?;
+        Ok(res.is_signee)
+    }
+
+    /// Checks if each of the addresses has an active signature, in a single query
+    pub fn are_signees(
+        &self,
+        querier: &QuerierWrapper,
+        manifesto_id: u64,
+        addresses: Vec<String>,
+    ) -> StdResult<Vec<SigneeResponse>> {
+        let res: SigneesResponse = smart_query(
+            querier,
+            &self.0,
+            &QueryMsg::AreSignees {
+                manifesto_id,
+                addresses,
+            },
+        )?;
+        Ok(res.signees)
+    }
+
+    /// Returns the signature of `signee`, including withdrawn ones. Fails if they never
+    /// signed, use `is_signee` to check if they did
+    pub fn signature(
+        &self,
+        querier: &QuerierWrapper,
+        manifesto_id: u64,
+        signee: impl Into<String>,
+    ) -> StdResult<SignatureResponse> {
+        smart_query(
+            querier,
+            &self.0,
+            &QueryMsg::GetSignature {
+                manifesto_id,
+                signee: signee.into(),
+            },
+        )
+    }
+}
+
+/// MEDAL contract, queried and called by other contracts
+#[derive(Clone, Debug, PartialEq)]
+pub struct MedalContract(pub Addr);
+
+impl MedalContract {
+    pub fn addr(&self) -> Addr {
+        self.0.clone()
+    }
+
+    /// Builds a message executing any `MedalExecuteMsg` on the MEDAL contract
+    pub fn call(&self, msg: MedalExecuteMsg, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+        execute_msg(&self.0, &msg, funds)
+    }
+
+    pub fn update_medal_redeem_config(
+        &self,
+        medal_redeem_addr: String,
+        metadata: MedalMetaData,
+    ) -> StdResult<CosmosMsg> {
+        self.call(
+            MedalExecuteMsg::UpdateMedalRedeemConfig {
+                medal_redeem_addr,
+                metadata,
+            },
+            vec![],
+        )
+    }
+
+    pub fn approve(
+        &self,
+        spender: String,
+        token_id: String,
+        expires: Option<cw721::Expiration>,
+    ) -> StdResult<CosmosMsg> {
+        self.call(
+            MedalExecuteMsg::Approve {
+                spender,
+                token_id,
+                expires,
+            },
+            vec![],
+        )
+    }
+
+    pub fn revoke(&self, spender: String, token_id: String) -> StdResult<CosmosMsg> {
+        self.call(MedalExecuteMsg::Revoke { spender, token_id }, vec![])
+    }
+
+    pub fn approve_all(
+        &self,
+        operator: String,
+        expires: Option<cw721::Expiration>,
+    ) -> StdResult<CosmosMsg> {
+        self.call(MedalExecuteMsg::ApproveAll { operator, expires }, vec![])
+    }
+
+    pub fn revoke_all(&self, operator: String) -> StdResult<CosmosMsg> {
+        self.call(MedalExecuteMsg::RevokeAll { operator }, vec![])
+    }
+
+    pub fn mint(&self, msg: MintMsg) -> StdResult<CosmosMsg> {
+        self.call(MedalExecuteMsg::Mint(msg), vec![])
+    }
+
+    pub fn update_guardian(&self, guardian: Option<String>) -> StdResult<CosmosMsg> {
+        self.call(MedalExecuteMsg::UpdateGuardian { guardian }, vec![])
+    }
+
+    pub fn pause(&self, flags: Vec<PauseFlag>) -> StdResult<CosmosMsg> {
+        self.call(MedalExecuteMsg::Pause { flags }, vec![])
+    }
+
+    pub fn unpause(&self, flags: Vec<PauseFlag>) -> StdResult<CosmosMsg> {
+        self.call(MedalExecuteMsg::Unpause { flags }, vec![])
+    }
+
+    pub fn transfer_nft(&self, recipient: String, token_id: String) -> StdResult<CosmosMsg> {
+        self.call(
+            MedalExecuteMsg::TransferNft {
+                recipient,
+                token_id,
+            },
+            vec![],
+        )
+    }
+
+    pub fn send_nft(
+        &self,
+        contract: String,
+        token_id: String,
+        msg: Binary,
+    ) -> StdResult<CosmosMsg> {
+        self.call(
+            MedalExecuteMsg::SendNft {
+                contract,
+                token_id,
+                msg,
+            },
+            vec![],
+        )
+    }
+
+    /// Redeems a MEDAL for a pin shipped to `region`. `funds` pay the redemption fee, if any
+    pub fn redeem_medal(
+        &self,
+        token_id: String,
+        region: Option<String>,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg> {
+        self.call(MedalExecuteMsg::RedeemMedal { token_id, region }, funds)
+    }
+
+    pub fn update_svg_template(&self, template: Option<String>) -> StdResult<CosmosMsg> {
+        self.call(MedalExecuteMsg::UpdateSvgTemplate { template }, vec![])
+    }
+
+    pub fn update_redemption_fee(
+        &self,
+        recipient: String,
+        regions: Vec<RegionFee>,
+    ) -> StdResult<CosmosMsg> {
+        self.call(
+            MedalExecuteMsg::UpdateRedemptionFee { recipient, regions },
+            vec![],
+        )
+    }
+
+    pub fn burn_medal(&self, token_id: String, owner: String) -> StdResult<CosmosMsg> {
+        self.call(MedalExecuteMsg::BurnMedal { token_id, owner }, vec![])
+    }
+
+    pub fn owner_of(
+        &self,
+        querier: &QuerierWrapper,
+        token_id: impl Into<String>,
+    ) -> StdResult<OwnerOfResponse> {
+        smart_query(
+            querier,
+            &self.0,
+            &Cw721QueryMsg::OwnerOf {
+                token_id: token_id.into(),
+                include_expired: None,
+            },
+        )
+    }
+
+    pub fn nft_info(
+        &self,
+        querier: &QuerierWrapper,
+        token_id: impl Into<String>,
+    ) -> StdResult<NftInfoResponse<Option<Metadata>>> {
+        smart_query(
+            querier,
+            &self.0,
+            &Cw721QueryMsg::NftInfo {
+                token_id: token_id.into(),
+            },
+        )
+    }
+
+    /// Total number of MEDALs minted and not redeemed or burnt
+    pub fn num_tokens(&self, querier: &QuerierWrapper) -> StdResult<u64> {
+        let res: NumTokensResponse = smart_query(querier, &self.0, &Cw721QueryMsg::NumTokens {})?;
+        Ok(res.count)
+    }
+
+    /// Returns true if `owner` holds at least one MEDAL
+    pub fn owns_medal(
+        &self,
+        querier: &QuerierWrapper,
+        owner: impl Into<String>,
+    ) -> StdResult<bool> {
+        let res = self.tokens(querier, owner.into(), None, Some(1))?;
+        Ok(!res.tokens.is_empty())
+    }
+
+    /// Number of MEDALs held by `owner`. Token ids are listed page by page, so the cost of the
+    /// query grows with the number of MEDALs held
+    pub fn medal_count(
+        &self,
+        querier: &QuerierWrapper,
+        owner: impl Into<String>,
+    ) -> StdResult<u64> {
+        let owner = owner.into();
+        let mut count = 0u64;
+        let mut start_after = None;
+        loop {
+            let res = self.tokens(querier, owner.clone(), start_after, Some(MEDAL_PAGE_LIMIT))?;
+            count += res.tokens.len() as u64;
+            if res.tokens.len() < MEDAL_PAGE_LIMIT as usize {
+                return Ok(count);
+            }
+            start_after = res.tokens.last().cloned();
+        }
+    }
+
+    /// Token ids of the MEDALs held by `owner`, a page at a time
+    pub fn tokens(
+        &self,
+        querier: &QuerierWrapper,
+        owner: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<TokensResponse> {
+        smart_query(
+            querier,
+            &self.0,
+            &Cw721QueryMsg::Tokens {
+                owner,
+                start_after,
+                limit,
+            },
+        )
+    }
+}
+
+fn execute_msg<T: Serialize>(
+    contract_addr: &Addr,
+    msg: &T,
+    funds: Vec<Coin>,
+) -> StdResult<CosmosMsg> {
+    Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: contract_addr.to_string(),
+        msg: to_binary(msg)?,
+        funds,
+    }))
+}
+
+fn smart_query<T: Serialize, U: DeserializeOwned>(
+    querier: &QuerierWrapper,
+    contract_addr: &Addr,
+    msg: &T,
+) -> StdResult<U> {
+    querier.query(
+        &WasmQuery::Smart {
+            contract_addr: contract_addr.to_string(),
+            msg: to_binary(msg)?,
+        }
+        .into(),
+    )
+}
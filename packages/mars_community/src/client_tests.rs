@@ -0,0 +1,204 @@
+#![cfg(test)]
+use cosmwasm_std::{
+    coin, from_binary, from_slice, to_binary, Addr, Binary, ContractResult, CosmosMsg, Empty,
+    Querier, QuerierResult, QuerierWrapper, QueryRequest, SystemResult, WasmMsg, WasmQuery,
+};
+use cw721::{Cw721QueryMsg, TokensResponse};
+
+use crate::client::{ManifestoContract, MedalContract};
+use crate::manifesto::{ExecuteMsg, MedalExecuteMsg, QueryMsg, SigneeResponse, SigneesResponse};
+use crate::pause::PauseFlag;
+
+const MANIFESTO: &str = "manifesto";
+const MEDAL: &str = "medal";
+
+/// Answers manifesto queries as if "signee" were the only signee, and MEDAL queries as if
+/// "holder" held MEDALs 1 to 45
+struct MockContracts;
+
+impl Querier for MockContracts {
+    fn raw_query(&self, bin_request: &[u8]) -> QuerierResult {
+        let (contract_addr, msg) = match from_slice(bin_request).unwrap() {
+            QueryRequest::<Empty>::Wasm(WasmQuery::Smart { contract_addr, msg }) => {
+                (contract_addr, msg)
+            }
+            _ => panic!("unexpected query"),
+        };
+        let res = match contract_addr.as_str() {
+            MANIFESTO => match from_binary(&msg).unwrap() {
+                QueryMsg::IsSignee { address, .. } => to_binary(&SigneeResponse {
+                    is_signee: address == "signee",
+                    address: Addr::unchecked(address),
+                }),
+                QueryMsg::AreSignees { addresses, .. } => to_binary(&SigneesResponse {
+                    signees: addresses
+                        .into_iter()
+                        .map(|address| SigneeResponse {
+                            is_signee: address == "signee",
+                            address: Addr::unchecked(address),
+                        })
+                        .collect(),
+                }),
+                _ => panic!("unexpected manifesto query"),
+            },
+            MEDAL => match from_binary(&msg).unwrap() {
+                Cw721QueryMsg::Tokens {
+                    owner,
+                    start_after,
+                    limit,
+                } => {
+                    let held = if owner == "holder" { 45 } else { 0 };
+                    let start =
+                        start_after.map_or(1, |token_id| token_id.parse::<u64>().unwrap() + 1);
+                    to_binary(&TokensResponse {
+                        tokens: (start..=held)
+                            .take(limit.unwrap() as usize)
+                            .map(|token_id| token_id.to_string())
+                            .collect(),
+                    })
+                }
+                _ => panic!("unexpected MEDAL query"),
+            },
+            _ => panic!("unexpected contract"),
+        };
+        SystemResult::Ok(ContractResult::Ok(res.unwrap()))
+    }
+}
+
+#[test]
+fn building_messages() {
+    let manifesto = ManifestoContract(Addr::unchecked(MANIFESTO));
+    let msg = manifesto
+        .sign_manifesto(1, "hash".to_string(), None, vec![coin(100, "uusd")])
+        .unwrap();
+    assert_eq!(
+        msg,
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: MANIFESTO.to_string(),
+            msg: to_binary(&ExecuteMsg::SignManifesto {
+                manifesto_id: 1,
+                content_hash: "hash".to_string(),
+                martian_date: None,
+                martian_time: None,
+                proof: None,
+            })
+            .unwrap(),
+            funds: vec![coin(100, "uusd")],
+        })
+    );
+
+    let msg = manifesto
+        .sign_manifesto_for(
+            1,
+            "hash".to_string(),
+            Binary::from(vec![2; 33]),
+            Binary::from(vec![1; 64]),
+            None,
+            None,
+            0,
+            None,
+            vec![],
+        )
+        .unwrap();
+    assert_eq!(
+        msg,
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: MANIFESTO.to_string(),
+            msg: to_binary(&ExecuteMsg::SignManifestoFor {
+                manifesto_id: 1,
+                content_hash: "hash".to_string(),
+                signee_pubkey: Binary::from(vec![2; 33]),
+                signature: Binary::from(vec![1; 64]),
+                martian_date: None,
+                martian_time: None,
+                nonce: 0,
+                proof: None,
+            })
+            .unwrap(),
+            funds: vec![],
+        })
+    );
+    assert_eq!(
+        manifesto.pause(vec![PauseFlag::Signing]).unwrap(),
+        manifesto
+            .call(
+                ExecuteMsg::Pause {
+                    flags: vec![PauseFlag::Signing]
+                },
+                vec![]
+            )
+            .unwrap()
+    );
+
+    let medal = MedalContract(Addr::unchecked(MEDAL));
+    assert_eq!(
+        medal
+            .burn_medal("1".to_string(), "holder".to_string())
+            .unwrap(),
+        medal
+            .call(
+                MedalExecuteMsg::BurnMedal {
+                    token_id: "1".to_string(),
+                    owner: "holder".to_string(),
+                },
+                vec![]
+            )
+            .unwrap()
+    );
+    let msg = medal
+        .redeem_medal(
+            "1".to_string(),
+            Some("EU".to_string()),
+            vec![coin(5000000, "uusd")],
+        )
+        .unwrap();
+    assert_eq!(
+        msg,
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: MEDAL.to_string(),
+            msg: to_binary(&MedalExecuteMsg::RedeemMedal {
+                token_id: "1".to_string(),
+                region: Some("EU".to_string()),
+            })
+            .unwrap(),
+            funds: vec![coin(5000000, "uusd")],
+        })
+    );
+}
+
+#[test]
+fn querying_signees() {
+    let querier = QuerierWrapper::new(&MockContracts);
+    let manifesto = ManifestoContract(Addr::unchecked(MANIFESTO));
+
+    assert!(manifesto.is_signee(&querier, 1, "signee").unwrap());
+    assert!(!manifesto.is_signee(&querier, 1, "stranger").unwrap());
+
+    let signees = manifesto
+        .are_signees(
+            &querier,
+            1,
+            vec!["stranger".to_string(), "signee".to_string()],
+        )
+        .unwrap();
+    assert_eq!(
+        signees
+            .iter()
+            .map(|signee| signee.is_signee)
+            .collect::<Vec<_>>(),
+        vec![false, true]
+    );
+}
+
+#[test]
+fn querying_medals() {
+    let querier = QuerierWrapper::new(&MockContracts);
+    let medal = MedalContract(Addr::unchecked(MEDAL));
+
+    assert!(medal.owns_medal(&querier, "holder").unwrap());
+    assert!(!medal.owns_medal(&querier, "stranger").unwrap());
+
+    // Counted over several pages
+    assert_eq!(medal.medal_count(&querier, "holder").unwrap(), 45);
+    assert_eq!(medal.medal_count(&querier, "stranger").unwrap(), 0);
+}
@@ -0,0 +1,432 @@
+//! Darian calendar and Coordinated Mars Time (MTC).
+//!
+//! Dates follow the [Darian Calendar](https://en.wikipedia.org/wiki/Darian_calendar) with the
+//! Telescopic Epoch (year 0), and time calculations follow the Mars24 algorithm referenced by
+//! https://marsclock.com/. All arithmetic is done on integers so that it can run inside a contract.
+//!
+//! Canonical string formats:
+//! - Date: `"<sol> <Month> <year>"`, e.g. `"26 Taurus 219"`
+//! - Time: `"HH:MM:SS MTC"`, e.g. `"09:05:33 MTC"`
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+use cosmwasm_std::Timestamp;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Names of the 24 Darian months, in calendar order
+pub const MONTH_NAMES: [&str; 24] = [
+    "Sagittarius",
+    "Dhanus",
+    "Capricornus",
+    "Makara",
+    "Aquarius",
+    "Kumbha",
+    "Pisces",
+    "Mina",
+    "Aries",
+    "Mesha",
+    "Taurus",
+    "Rishabha",
+    "Gemini",
+    "Mithuna",
+    "Cancer",
+    "Karka",
+    "Leo",
+    "Simha",
+    "Virgo",
+    "Kanya",
+    "Libra",
+    "Tula",
+    "Scorpius",
+    "Vrishika",
+];
+
+/// Suffix of a formatted Coordinated Mars Time
+pub const MTC_SUFFIX: &str = " MTC";
+
+/// Length of a Martian solar day in nanoseconds (1.0274912517 Earth days)
+pub const SOL_NANOS: u128 = 88_775_244_146_880;
+
+/// Earth nanoseconds from the Mars Sol Date epoch to the Unix epoch, including TT - UTC
+/// (69.184 seconds) so that block time (UTC) maps onto Terrestrial Time
+const MSD_EPOCH_TO_UNIX_NANOS: u128 = 3_029_659_020_533_440_000;
+
+/// Mars Sol Date of sol 1 Sagittarius of Darian year 0
+pub const DARIAN_EPOCH_MSD: i64 = -94_129;
+
+const SOLS_PER_YEAR: i64 = 668;
+/// Sols in a full 500 year intercalation cycle
+const SOLS_PER_CYCLE: i64 = 500 * SOLS_PER_YEAR + 296;
+const MARS_SECONDS_PER_SOL: u128 = 86_400;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum DarianError {
+    #[error("Invalid Martian Date: {value:?} is not in the \"<sol> <Month> <year>\" format")]
+    MalformedDate { value: String },
+
+    #[error("Invalid Martian Date: {month:?} is not a Darian month")]
+    UnknownMonth { month: String },
+
+    #[error("Invalid Martian Date: {month} {year} has {max} sols, got sol {sol}")]
+    SolOutOfRange {
+        sol: u32,
+        month: String,
+        year: u32,
+        max: u8,
+    },
+
+    #[error("Invalid Martian Date: MSD {msd} is before the Darian epoch")]
+    BeforeEpoch { msd: i64 },
+
+    #[error("Invalid Martian Time: {value:?} is not in the \"HH:MM:SS MTC\" format")]
+    MalformedTime { value: String },
+
+    #[error("Invalid Martian Time: {hours:02}:{minutes:02}:{seconds:02} is out of range")]
+    TimeOutOfRange { hours: u8, minutes: u8, seconds: u8 },
+
+    #[error("Martian date and time are before the Unix epoch")]
+    BeforeUnixEpoch {},
+
+    #[error("Martian date and time are too far in the future to be a block time")]
+    AfterTimestampRange {},
+}
+
+/// A date in the Darian calendar. `month` is 1-based (1 = Sagittarius)
+#[derive(
+    Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, JsonSchema,
+)]
+#[serde(try_from = "UncheckedMartianDate")]
+pub struct MartianDate {
+    pub year: u32,
+    pub month: u8,
+    pub sol: u8,
+}
+
+/// A Coordinated Mars Time, i.e. the mean solar time at the Martian prime meridian
+#[derive(
+    Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, JsonSchema,
+)]
+#[serde(try_from = "UncheckedMartianTime")]
+pub struct MartianTime {
+    pub hours: u8,
+    pub minutes: u8,
+    pub seconds: u8,
+}
+
+/// Deserialized fields of a `MartianDate`, validated by `MartianDate::new`
+#[derive(Deserialize)]
+struct UncheckedMartianDate {
+    year: u32,
+    month: u8,
+    sol: u8,
+}
+
+/// Deserialized fields of a `MartianTime`, validated by `MartianTime::new`
+#[derive(Deserialize)]
+struct UncheckedMartianTime {
+    hours: u8,
+    minutes: u8,
+    seconds: u8,
+}
+
+//----------------------------------------------------------------------------------------
+// Calendar rules
+//----------------------------------------------------------------------------------------
+
+/// Returns true if the Darian year has 669 sols. Odd years and years divisible by 10 are
+/// leap years, except years divisible by 100 that are not divisible by 500
+pub fn is_leap_year(year: u32) -> bool {
+    if year % 500 == 0 {
+        return true;
+    }
+    if year % 100 == 0 {
+        return false;
+    }
+    year % 2 == 1 || year % 10 == 0
+}
+
+/// Returns the number of sols in a Darian year
+pub fn sols_in_year(year: u32) -> u16 {
+    if is_leap_year(year) {
+        669
+    } else {
+        668
+    }
+}
+
+/// Returns the number of sols in a month (1-based) of a Darian year. Every sixth month has 27
+/// sols, except Vrishika in leap years
+pub fn sols_in_month(year: u32, month: u8) -> u8 {
+    if month == 24 && is_leap_year(year) {
+        28
+    } else if month % 6 == 0 {
+        27
+    } else {
+        28
+    }
+}
+
+/// Returns the name of a month (1-based), or None if out of range
+pub fn month_name(month: u8) -> Option<&'static str> {
+    MONTH_NAMES.get((month as usize).checked_sub(1)?).copied()
+}
+
+/// Returns the 1-based index of a month from its name
+pub fn month_from_name(name: &str) -> Option<u8> {
+    MONTH_NAMES
+        .iter()
+        .position(|month| *month == name)
+        .map(|idx| idx as u8 + 1)
+}
+
+//----------------------------------------------------------------------------------------
+// Martian Date
+//----------------------------------------------------------------------------------------
+
+impl MartianDate {
+    /// Returns a validated Darian date
+    pub fn new(year: u32, month: u8, sol: u8) -> Result<Self, DarianError> {
+        Self::checked(year, month, sol as u32)
+    }
+
+    /// Returns a validated Darian date, with the sol before its conversion to u8 so that errors
+    /// report it as given
+    fn checked(year: u32, month: u8, sol: u32) -> Result<Self, DarianError> {
+        let month_name = month_name(month).ok_or_else(|| DarianError::UnknownMonth {
+            month: month.to_string(),
+        })?;
+        let max = sols_in_month(year, month);
+        if sol == 0 || sol > max as u32 {
+            return Err(DarianError::SolOutOfRange {
+                sol,
+                month: month_name.to_string(),
+                year,
+                max,
+            });
+        }
+        Ok(MartianDate {
+            year,
+            month,
+            sol: sol as u8,
+        })
+    }
+
+    /// Returns the name of the month
+    pub fn month_name(&self) -> &'static str {
+        month_name(self.month).unwrap_or_default()
+    }
+
+    /// Returns the Darian date of a Mars Sol Date
+    pub fn from_mars_sol_date(msd: i64) -> Result<Self, DarianError> {
+        let mut sols = msd - DARIAN_EPOCH_MSD;
+        if sols < 0 {
+            return Err(DarianError::BeforeEpoch { msd });
+        }
+
+        let mut year = (sols / SOLS_PER_CYCLE) as u32 * 500;
+        sols %= SOLS_PER_CYCLE;
+        while sols >= sols_in_year(year) as i64 {
+            sols -= sols_in_year(year) as i64;
+            year += 1;
+        }
+
+        let mut month = 1u8;
+        while sols >= sols_in_month(year, month) as i64 {
+            sols -= sols_in_month(year, month) as i64;
+            month += 1;
+        }
+
+        Ok(MartianDate {
+            year,
+            month,
+            sol: sols as u8 + 1,
+        })
+    }
+
+    /// Returns the number of sols between the Darian epoch and this date
+    pub fn sols_since_epoch(&self) -> u64 {
+        (self.to_mars_sol_date() - DARIAN_EPOCH_MSD) as u64
+    }
+
+    /// Returns the Mars Sol Date of this Darian date
+    pub fn to_mars_sol_date(&self) -> i64 {
+        let mut sols = (self.year / 500) as i64 * SOLS_PER_CYCLE;
+        for year in (self.year - self.year % 500)..self.year {
+            sols += sols_in_year(year) as i64;
+        }
+        for month in 1..self.month {
+            sols += sols_in_month(self.year, month) as i64;
+        }
+        sols + self.sol as i64 - 1 + DARIAN_EPOCH_MSD
+    }
+}
+
+impl fmt::Display for MartianDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {} {}", self.sol, self.month_name(), self.year)
+    }
+}
+
+impl FromStr for MartianDate {
+    type Err = DarianError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let malformed = || DarianError::MalformedDate {
+            value: value.to_string(),
+        };
+
+        let parts: Vec<&str> = value.split(' ').collect();
+        if parts.len() != 3 {
+            return Err(malformed());
+        }
+
+        let sol = parse_number(parts[0]).ok_or_else(malformed)?;
+        let month = month_from_name(parts[1]).ok_or_else(|| DarianError::UnknownMonth {
+            month: parts[1].to_string(),
+        })?;
+        let year = parse_number(parts[2]).ok_or_else(malformed)?;
+
+        MartianDate::checked(year, month, sol)
+    }
+}
+
+impl TryFrom<UncheckedMartianDate> for MartianDate {
+    type Error = DarianError;
+
+    fn try_from(date: UncheckedMartianDate) -> Result<Self, Self::Error> {
+        MartianDate::new(date.year, date.month, date.sol)
+    }
+}
+
+//----------------------------------------------------------------------------------------
+// Martian Time
+//----------------------------------------------------------------------------------------
+
+impl MartianTime {
+    /// Returns a validated Coordinated Mars Time
+    pub fn new(hours: u8, minutes: u8, seconds: u8) -> Result<Self, DarianError> {
+        if hours > 23 || minutes > 59 || seconds > 59 {
+            return Err(DarianError::TimeOutOfRange {
+                hours,
+                minutes,
+                seconds,
+            });
+        }
+        Ok(MartianTime {
+            hours,
+            minutes,
+            seconds,
+        })
+    }
+
+    /// Returns the number of Martian seconds elapsed since midnight
+    pub fn seconds_of_sol(&self) -> u32 {
+        self.hours as u32 * 3_600 + self.minutes as u32 * 60 + self.seconds as u32
+    }
+}
+
+impl fmt::Display for MartianTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:02}:{:02}:{:02}{}",
+            self.hours, self.minutes, self.seconds, MTC_SUFFIX
+        )
+    }
+}
+
+impl FromStr for MartianTime {
+    type Err = DarianError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let malformed = || DarianError::MalformedTime {
+            value: value.to_string(),
+        };
+
+        let clock = value.strip_suffix(MTC_SUFFIX).ok_or_else(malformed)?;
+        let parts: Vec<&str> = clock.split(':').collect();
+        if parts.len() != 3 || parts.iter().any(|part| part.len() != 2) {
+            return Err(malformed());
+        }
+
+        let mut fields = [0u8; 3];
+        for (field, part) in fields.iter_mut().zip(parts) {
+            if !part.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(malformed());
+            }
+            *field = part.parse().map_err(|_| malformed())?;
+        }
+
+        MartianTime::new(fields[0], fields[1], fields[2])
+    }
+}
+
+impl TryFrom<UncheckedMartianTime> for MartianTime {
+    type Error = DarianError;
+
+    fn try_from(time: UncheckedMartianTime) -> Result<Self, Self::Error> {
+        MartianTime::new(time.hours, time.minutes, time.seconds)
+    }
+}
+
+//----------------------------------------------------------------------------------------
+// Earth <> Mars conversions
+//----------------------------------------------------------------------------------------
+
+/// Returns the Mars Sol Date (whole sols) at an Earth time
+pub fn mars_sol_date(time: Timestamp) -> i64 {
+    (mars_nanos(time) / SOL_NANOS) as i64
+}
+
+/// Returns the Coordinated Mars Time at an Earth time
+pub fn coordinated_mars_time(time: Timestamp) -> MartianTime {
+    let nanos_of_sol = mars_nanos(time) % SOL_NANOS;
+    let seconds = (nanos_of_sol * MARS_SECONDS_PER_SOL / SOL_NANOS) as u32;
+    MartianTime {
+        hours: (seconds / 3_600) as u8,
+        minutes: (seconds / 60 % 60) as u8,
+        seconds: (seconds % 60) as u8,
+    }
+}
+
+/// Returns the Darian date and Coordinated Mars Time at an Earth time
+pub fn from_timestamp(time: Timestamp) -> (MartianDate, MartianTime) {
+    let date = MartianDate::from_mars_sol_date(mars_sol_date(time))
+        .expect("Unix times are after the Darian epoch");
+    (date, coordinated_mars_time(time))
+}
+
+/// Returns the Earth time at which a Darian date and Coordinated Mars Time begins
+pub fn to_timestamp(date: &MartianDate, time: &MartianTime) -> Result<Timestamp, DarianError> {
+    let msd =
+        u128::try_from(date.to_mars_sol_date()).map_err(|_| DarianError::BeforeUnixEpoch {})?;
+    // Rounded up, to the first Earth nanosecond of the Martian second
+    let nanos_of_sol = (time.seconds_of_sol() as u128 * SOL_NANOS + MARS_SECONDS_PER_SOL - 1)
+        / MARS_SECONDS_PER_SOL;
+    let nanos = (msd * SOL_NANOS + nanos_of_sol)
+        .checked_sub(MSD_EPOCH_TO_UNIX_NANOS)
+        .ok_or(DarianError::BeforeUnixEpoch {})?;
+    let nanos = u64::try_from(nanos).map_err(|_| DarianError::AfterTimestampRange {})?;
+    Ok(Timestamp::from_nanos(nanos))
+}
+
+/// Earth nanoseconds elapsed since the Mars Sol Date epoch
+fn mars_nanos(time: Timestamp) -> u128 {
+    time.nanos() as u128 + MSD_EPOCH_TO_UNIX_NANOS
+}
+
+/// Parses a base 10 number without sign or leading zeros
+fn parse_number(value: &str) -> Option<u32> {
+    let bytes = value.as_bytes();
+    if bytes.is_empty() || (bytes.len() > 1 && bytes[0] == b'0') {
+        return None;
+    }
+    if !bytes.iter().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    value.parse().ok()
+}
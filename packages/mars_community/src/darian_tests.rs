@@ -0,0 +1,206 @@
+#![cfg(test)]
+use cosmwasm_std::{from_slice, Timestamp};
+
+use crate::darian::{
+    coordinated_mars_time, from_timestamp, is_leap_year, mars_sol_date, sols_in_month,
+    sols_in_year, to_timestamp, DarianError, MartianDate, MartianTime, DARIAN_EPOCH_MSD,
+};
+
+#[test]
+fn leap_years() {
+    // odd years
+    assert!(is_leap_year(219));
+    assert!(!is_leap_year(218));
+    // years divisible by 10
+    assert!(is_leap_year(220));
+    // years divisible by 100 unless divisible by 500
+    assert!(!is_leap_year(200));
+    assert!(is_leap_year(500));
+    assert!(is_leap_year(0));
+
+    assert_eq!(669, sols_in_year(219));
+    assert_eq!(668, sols_in_year(218));
+
+    let total: u16 = (1..=24).map(|month| sols_in_month(218, month) as u16).sum();
+    assert_eq!(668, total);
+    let total: u16 = (1..=24).map(|month| sols_in_month(219, month) as u16).sum();
+    assert_eq!(669, total);
+}
+
+#[test]
+fn parse_and_format_dates() {
+    let date: MartianDate = "26 Taurus 219".parse().unwrap();
+    assert_eq!(
+        date,
+        MartianDate {
+            year: 219,
+            month: 11,
+            sol: 26
+        }
+    );
+    assert_eq!("Taurus", date.month_name());
+    assert_eq!("26 Taurus 219", date.to_string());
+
+    // malformed dates
+    for value in [
+        "",
+        "aaaaaaaaaaaa",
+        "26  Taurus 219",
+        "026 Taurus 219",
+        "26 Taurus",
+        "-1 Leo 2",
+    ] {
+        assert_eq!(
+            value.parse::<MartianDate>().unwrap_err(),
+            DarianError::MalformedDate {
+                value: value.to_string()
+            }
+        );
+    }
+
+    // unknown month
+    assert_eq!(
+        "26 taurus 219".parse::<MartianDate>().unwrap_err(),
+        DarianError::UnknownMonth {
+            month: "taurus".to_string()
+        }
+    );
+
+    // impossible dates
+    assert_eq!(
+        "28 Kumbha 219".parse::<MartianDate>().unwrap_err(),
+        DarianError::SolOutOfRange {
+            sol: 28,
+            month: "Kumbha".to_string(),
+            year: 219,
+            max: 27
+        }
+    );
+    assert!("28 Vrishika 219".parse::<MartianDate>().is_ok());
+    assert!("28 Vrishika 218".parse::<MartianDate>().is_err());
+    assert!("0 Leo 219".parse::<MartianDate>().is_err());
+    assert_eq!(
+        "300 Leo 219".parse::<MartianDate>().unwrap_err(),
+        DarianError::SolOutOfRange {
+            sol: 300,
+            month: "Leo".to_string(),
+            year: 219,
+            max: 28
+        }
+    );
+}
+
+#[test]
+fn deserialize_validated_dates_and_times() {
+    let date: MartianDate = from_slice(br#"{"year":219,"month":11,"sol":26}"#).unwrap();
+    assert_eq!("26 Taurus 219", date.to_string());
+    for value in [
+        r#"{"year":219,"month":0,"sol":1}"#,
+        r#"{"year":219,"month":30,"sol":1}"#,
+        r#"{"year":219,"month":11,"sol":99}"#,
+    ] {
+        assert!(from_slice::<MartianDate>(value.as_bytes()).is_err());
+    }
+
+    let time: MartianTime = from_slice(br#"{"hours":9,"minutes":5,"seconds":33}"#).unwrap();
+    assert_eq!("09:05:33 MTC", time.to_string());
+    assert!(from_slice::<MartianTime>(br#"{"hours":24,"minutes":0,"seconds":0}"#).is_err());
+}
+
+#[test]
+fn parse_and_format_times() {
+    let time: MartianTime = "09:05:33 MTC".parse().unwrap();
+    assert_eq!(
+        time,
+        MartianTime {
+            hours: 9,
+            minutes: 5,
+            seconds: 33
+        }
+    );
+    assert_eq!("09:05:33 MTC", time.to_string());
+
+    for value in [
+        "aaaaaaaaaaaa",
+        "09:05:33",
+        "9:05:33 MTC",
+        "09:05:3a MTC",
+        "09-05-33 MTC",
+    ] {
+        assert_eq!(
+            value.parse::<MartianTime>().unwrap_err(),
+            DarianError::MalformedTime {
+                value: value.to_string()
+            }
+        );
+    }
+
+    assert_eq!(
+        "24:00:00 MTC".parse::<MartianTime>().unwrap_err(),
+        DarianError::TimeOutOfRange {
+            hours: 24,
+            minutes: 0,
+            seconds: 0
+        }
+    );
+    assert!("23:60:00 MTC".parse::<MartianTime>().is_err());
+    assert!("23:59:60 MTC".parse::<MartianTime>().is_err());
+}
+
+#[test]
+fn mars_sol_date_conversions() {
+    assert_eq!(
+        MartianDate::new(0, 1, 1).unwrap().to_mars_sol_date(),
+        DARIAN_EPOCH_MSD
+    );
+    assert_eq!(
+        MartianDate::from_mars_sol_date(DARIAN_EPOCH_MSD - 1).unwrap_err(),
+        DarianError::BeforeEpoch {
+            msd: DARIAN_EPOCH_MSD - 1
+        }
+    );
+
+    // Darian year 219 starts at the northern vernal equinox of Mars Year 36
+    let new_year = MartianDate::new(219, 1, 1).unwrap();
+    assert_eq!(52292, new_year.to_mars_sol_date());
+
+    for msd in (DARIAN_EPOCH_MSD..400_000).step_by(97) {
+        let date = MartianDate::from_mars_sol_date(msd).unwrap();
+        assert_eq!(msd, date.to_mars_sol_date());
+        assert_eq!(date, date.to_string().parse().unwrap());
+    }
+}
+
+#[test]
+fn earth_time_conversions() {
+    // Perseverance landing, 2021-02-18T20:30:00Z
+    let landing = Timestamp::from_seconds(1_613_680_200);
+    assert_eq!(52304, mars_sol_date(landing));
+    let (date, time) = from_timestamp(landing);
+    assert_eq!("13 Sagittarius 219", date.to_string());
+    assert_eq!(time, coordinated_mars_time(landing));
+    assert_eq!("10:30:11 MTC", time.to_string());
+
+    // Converting back returns the start of the Martian second
+    let start = to_timestamp(&date, &time).unwrap();
+    assert!(start <= landing);
+    assert!(landing.seconds() - start.seconds() <= 1);
+    assert_eq!((date, time), from_timestamp(start));
+
+    assert_eq!(
+        to_timestamp(
+            &MartianDate::new(100, 1, 1).unwrap(),
+            &MartianTime::new(0, 0, 0).unwrap()
+        )
+        .unwrap_err(),
+        DarianError::BeforeUnixEpoch {}
+    );
+    assert_eq!(
+        to_timestamp(
+            &MartianDate::new(1000, 1, 1).unwrap(),
+            &MartianTime::new(0, 0, 0).unwrap()
+        )
+        .unwrap_err(),
+        DarianError::AfterTimestampRange {}
+    );
+}
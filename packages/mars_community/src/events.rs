@@ -0,0 +1,93 @@
+//! Events emitted by the manifesto, MEDAL and R-MEDAL contracts for indexers, besides the
+//! attributes of their `wasm` event. Their type is prefixed with `wasm-` on chain. Keys mean the
+//! same in every event:
+//!
+//! - `contract_version`: version of the emitting contract, in all events
+//! - `owner`: holder of the token after the event (the signee for `manifesto_sign`)
+//! - `manifesto_id`, `ordinal`, `martian_date`: of the signature. The ordinal is also the id of
+//!   the MEDAL of the signee
+//! - `token_id`: token minted, redeemed or transferred
+//! - `redeemed_token_id`: R-MEDAL minted for a redeemed MEDAL
+//! - `sender`: address which transferred the token, the owner or an approved operator
+
+use cosmwasm_std::{Addr, Event};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+pub const MANIFESTO_SIGN_EVENT: &str = "manifesto_sign";
+pub const MEDAL_MINT_EVENT: &str = "medal_mint";
+pub const MEDAL_REDEEM_EVENT: &str = "medal_redeem";
+pub const MEDAL_TRANSFER_EVENT: &str = "medal_transfer";
+
+/// `data` of a new signature
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ManifestoSignData {
+    pub manifesto_id: u64,
+    pub ordinal: u64,
+}
+
+/// `data` of a MEDAL or R-MEDAL mint
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MedalMintData {
+    pub token_id: String,
+}
+
+/// `data` of a MEDAL redemption
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MedalRedeemData {
+    pub token_id: String,
+    pub redeemed_token_id: String,
+}
+
+/// Emitted by the manifesto contract for every new signature, including imported ones. The
+/// MEDAL of the signee is minted separately, see `medal_mint_event`
+pub fn manifesto_sign_event(
+    contract_version: &str,
+    manifesto_id: u64,
+    signee: &Addr,
+    ordinal: u64,
+    martian_date: &str,
+) -> Event {
+    Event::new(MANIFESTO_SIGN_EVENT)
+        .add_attribute("contract_version", contract_version)
+        .add_attribute("manifesto_id", manifesto_id.to_string())
+        .add_attribute("owner", signee)
+        .add_attribute("ordinal", ordinal.to_string())
+        .add_attribute("martian_date", martian_date)
+}
+
+/// Emitted by the MEDAL and R-MEDAL contracts when a token is minted
+pub fn medal_mint_event(contract_version: &str, token_id: &str, owner: &str) -> Event {
+    Event::new(MEDAL_MINT_EVENT)
+        .add_attribute("contract_version", contract_version)
+        .add_attribute("token_id", token_id)
+        .add_attribute("owner", owner)
+}
+
+/// Emitted by the MEDAL contract when a MEDAL is burnt for an R-MEDAL
+pub fn medal_redeem_event(
+    contract_version: &str,
+    token_id: &str,
+    owner: &Addr,
+    redeemed_token_id: &str,
+) -> Event {
+    Event::new(MEDAL_REDEEM_EVENT)
+        .add_attribute("contract_version", contract_version)
+        .add_attribute("token_id", token_id)
+        .add_attribute("owner", owner)
+        .add_attribute("redeemed_token_id", redeemed_token_id)
+}
+
+/// Emitted by the MEDAL and R-MEDAL contracts when a token is transferred or sent
+pub fn medal_transfer_event(
+    contract_version: &str,
+    token_id: &str,
+    sender: &Addr,
+    owner: &Addr,
+) -> Event {
+    Event::new(MEDAL_TRANSFER_EVENT)
+        .add_attribute("contract_version", contract_version)
+        .add_attribute("token_id", token_id)
+        .add_attribute("sender", sender)
+        .add_attribute("owner", owner)
+}
@@ -0,0 +1,60 @@
+#![cfg(test)]
+use cosmwasm_std::{attr, Addr};
+
+use crate::events::{
+    manifesto_sign_event, medal_mint_event, medal_redeem_event, medal_transfer_event,
+    MANIFESTO_SIGN_EVENT, MEDAL_MINT_EVENT, MEDAL_REDEEM_EVENT, MEDAL_TRANSFER_EVENT,
+};
+
+#[test]
+fn building_events() {
+    let owner = Addr::unchecked("owner");
+
+    let event = manifesto_sign_event("2.0.0", 1, &owner, 3, "13 Mina 218");
+    assert_eq!(MANIFESTO_SIGN_EVENT, event.ty);
+    assert_eq!(
+        event.attributes,
+        vec![
+            attr("contract_version", "2.0.0"),
+            attr("manifesto_id", "1"),
+            attr("owner", "owner"),
+            attr("ordinal", "3"),
+            attr("martian_date", "13 Mina 218"),
+        ]
+    );
+
+    let event = medal_mint_event("1.1.0", "3", "owner");
+    assert_eq!(MEDAL_MINT_EVENT, event.ty);
+    assert_eq!(
+        event.attributes,
+        vec![
+            attr("contract_version", "1.1.0"),
+            attr("token_id", "3"),
+            attr("owner", "owner"),
+        ]
+    );
+
+    let event = medal_redeem_event("1.1.0", "3", &owner, "1");
+    assert_eq!(MEDAL_REDEEM_EVENT, event.ty);
+    assert_eq!(
+        event.attributes,
+        vec![
+            attr("contract_version", "1.1.0"),
+            attr("token_id", "3"),
+            attr("owner", "owner"),
+            attr("redeemed_token_id", "1"),
+        ]
+    );
+
+    let event = medal_transfer_event("1.1.0", "3", &Addr::unchecked("operator"), &owner);
+    assert_eq!(MEDAL_TRANSFER_EVENT, event.ty);
+    assert_eq!(
+        event.attributes,
+        vec![
+            attr("contract_version", "1.1.0"),
+            attr("token_id", "3"),
+            attr("sender", "operator"),
+            attr("owner", "owner"),
+        ]
+    );
+}
@@ -1,2 +1,13 @@
+pub mod client;
+mod client_tests;
+pub mod darian;
+mod darian_tests;
+pub mod events;
+mod events_tests;
 pub mod manifesto;
+pub mod medal;
 pub mod metadata;
+pub mod pause;
+mod pause_tests;
+pub mod tax;
+mod tax_tests;
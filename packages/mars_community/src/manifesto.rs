@@ -1,95 +1,497 @@
+use crate::darian::{mars_sol_date, to_timestamp, DarianError, MartianDate, MartianTime};
+use crate::medal;
 use crate::metadata::Metadata;
-use cosmwasm_std::{Addr, Api, StdResult};
+use crate::pause::PauseFlag;
+use cosmwasm_std::{Addr, Api, Binary, BlockInfo, Coin, StdResult, Timestamp};
+use cw20::Expiration;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct InstantiateMsg {
-    pub medal_addr: Option<String>,
-    pub medal_redeem_addr: Option<String>,
-    pub max_signees_limit: u64,
     pub admin: String,
+    /// Max drift (in seconds) allowed between a signee's Martian date and time and block time
+    pub time_tolerance: Option<u64>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, JsonSchema)]
-pub struct MigrateMsg {}
+pub struct MigrateMsg {
+    /// Title of the manifesto the signatures of v1.0.0 are moved to (manifesto id 1).
+    /// Only required when migrating from v1.0.0
+    pub title: Option<String>,
+    /// Hex encoded SHA-256 hash of its text. Only required when migrating from v1.0.0
+    pub content_hash: Option<String>,
+    /// Where its text can be found
+    pub uri: Option<String>,
+}
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ExecuteMsg {
-    UpdateAdmin {
+    /// Proposes a new admin, who has to claim the role before `expires`
+    ProposeNewAdmin {
         new_admin: String,
+        expires: Expiration,
+    },
+    /// Accepts the admin role, sent by the proposed admin
+    ClaimAdmin {},
+    /// Cancels the pending admin proposal
+    DropAdminProposal {},
+    /// Registers a new manifesto. Its id is the number of manifestos registered so far
+    RegisterManifesto {
+        title: String,
+        /// Hex encoded SHA-256 hash of the manifesto text
+        content_hash: String,
+        /// Where the manifesto text can be found
+        uri: Option<String>,
+        max_signees_limit: u64,
+        medal_addr: Option<String>,
+        medal_redeem_addr: Option<String>,
+    },
+    UpdateManifesto {
+        manifesto_id: u64,
+        title: Option<String>,
+        max_signees_limit: Option<u64>,
+    },
+    /// Publishes a new version of the manifesto text. Signees of previous versions may re-sign it
+    AmendManifesto {
+        manifesto_id: u64,
+        content_hash: String,
+        uri: Option<String>,
     },
     UpdateMedalConfig {
+        manifesto_id: u64,
         medal_addr: String,
         metadata: Metadata,
     },
     UpdateMedalRedeemConfig {
+        manifesto_id: u64,
         medal_redeem_addr: String,
         metadata: MedalMetaData,
     },
+    /// Sets the template of the traits of MEDALs minted from now on. Resets it to the default
+    /// one (Martian date and time) if None
+    UpdateMedalTemplate {
+        manifesto_id: u64,
+        template: Option<MedalTemplate>,
+    },
+    /// Sets (or removes, if None) the SVG template the MEDAL contract of a manifesto renders the
+    /// `image_data` of its MEDALs from
+    UpdateMedalSvgTemplate {
+        manifesto_id: u64,
+        template: Option<String>,
+    },
+    /// Sets the fee the MEDAL contract of a manifesto charges to redeem a MEDAL for a physical
+    /// pin, forwarded to `recipient`. Redemption is free if `regions` is empty
+    UpdateMedalRedemptionFee {
+        manifesto_id: u64,
+        recipient: String,
+        regions: Vec<RegionFee>,
+    },
+    /// Replaces the MEDAL tiers. MEDALs of signees whose ordinal falls in a tier use its
+    /// metadata instead of the manifesto's one
+    UpdateMedalTiers {
+        manifesto_id: u64,
+        tiers: Vec<MedalTier>,
+    },
+    UpdateTimeTolerance {
+        time_tolerance: u64,
+    },
+    /// Replaces the signing phases of a manifesto. Signing is open at all times if there are none
+    UpdateSigningPhases {
+        manifesto_id: u64,
+        phases: Vec<SigningPhase>,
+    },
+    /// Sets (or removes, if None) the Merkle root of addresses allowed to sign a manifesto during
+    /// a signing round. The round is the id of the signing phase, or 0 if there are no phases
+    UpdateMerkleRoot {
+        manifesto_id: u64,
+        round: u32,
+        merkle_root: Option<String>,
+    },
+    /// Sets the signing fee, which signees pay in one of the `amounts` and which is forwarded to
+    /// `treasury`. Signing is free if `amounts` is empty
+    UpdateSigningFee {
+        treasury: String,
+        amounts: Vec<Coin>,
+    },
+    /// Sets (or removes, if None) the guardian, who may pause operations but not unpause them
+    UpdateGuardian {
+        guardian: Option<String>,
+    },
+    /// Pauses operations, sent by the guardian or the admin
+    Pause {
+        flags: Vec<PauseFlag>,
+    },
+    /// Lifts the pause of operations, sent by the admin
+    Unpause {
+        flags: Vec<PauseFlag>,
+    },
+    /// Withdraws the signature of the sender and burns their MEDAL if they still hold it
+    WithdrawSignature {
+        manifesto_id: u64,
+    },
+    /// Martian date and time are derived from block time. If provided, they must be within
+    /// `time_tolerance` seconds of block time. `proof` is required if the current signing round
+    /// has a Merkle root. `content_hash` must be the hash of the current version of the text.
+    /// Signees of a previous version re-sign the current one, keeping their MEDAL
     SignManifesto {
-        martian_date: String,
-        martian_time: String,
+        manifesto_id: u64,
+        content_hash: String,
+        martian_date: Option<String>,
+        martian_time: Option<String>,
+        proof: Option<Vec<String>>,
+    },
+    /// Signs the Manifesto on behalf of the owner of `signee_pubkey`, who signed a
+    /// `SignManifestoPayload` off-chain. Anyone can relay it and pay the gas and signing fees.
+    /// The MEDAL is minted to the address of `signee_pubkey`
+    SignManifestoFor {
+        manifesto_id: u64,
+        content_hash: String,
+        /// Compressed secp256k1 public key of the signee
+        signee_pubkey: Binary,
+        /// secp256k1 signature of the SHA-256 hash of the JSON encoded `SignManifestoPayload`
+        signature: Binary,
+        martian_date: Option<String>,
+        martian_time: Option<String>,
+        /// Must match the next nonce of the signee, see `QueryMsg::Nonce`
+        nonce: u64,
+        proof: Option<Vec<String>>,
     },
+    /// Mints again the MEDAL of the sender, if minting it failed when they signed
+    RetryMedalMint {
+        manifesto_id: u64,
+    },
+    /// Imports signatures gathered off-chain or on another deployment, unless signing is
+    /// paused. Entries must continue the ordinals of the manifesto. MEDALs are minted in the same
+    /// transaction if `mint_medals` is set, and pending until their mint succeeds. Signees can
+    /// mint pending ones with `RetryMedalMint`
+    ImportSignatures {
+        manifesto_id: u64,
+        entries: Vec<ImportedSignature>,
+        mint_medals: bool,
+    },
+    /// Permanently disables `ImportSignatures`
+    FinalizeImport {},
+    /// Moves signatures of a migrated v1.0.0 contract to manifesto 1, given the token id of the
+    /// MEDAL v1.0.0 minted to each signee, which was their signing order. Manifesto 1 can't be
+    /// signed until all of them are moved
+    MigrateLegacySignatures {
+        entries: Vec<LegacyMedal>,
+    },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct LegacyMedal {
+    pub signee: String,
+    /// Token id of the MEDAL minted to the signee by v1.0.0
+    pub token_id: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ImportedSignature {
+    pub signee: String,
+    /// e.g. "26 Taurus 219"
+    pub martian_date: String,
+    /// e.g. "09:05:33 MTC"
+    pub martian_time: String,
+    /// Signing order on the original deployment
+    pub ordinal: u64,
+}
+
+/// Payload signed off-chain by a signee whose signature is relayed with `SignManifestoFor`
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SignManifestoPayload {
+    /// Chain id and address of the manifesto contract, so the payload can't be replayed
+    /// on another chain or contract
+    pub chain_id: String,
+    pub contract_addr: String,
+    pub manifesto_id: u64,
+    pub content_hash: String,
+    pub martian_date: Option<String>,
+    pub martian_time: Option<String>,
+    pub nonce: u64,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum QueryMsg {
     Config {},
-    State {},
-    GetSignature { signee: String },
+    /// Returns the pending admin proposal, if any
+    AdminProposal {},
+    Manifesto {
+        manifesto_id: u64,
+    },
+    /// Lists manifestos by id
+    Manifestos {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Returns a version of the manifesto text (defaults to the current one)
+    ManifestoVersion {
+        manifesto_id: u64,
+        version: Option<u32>,
+    },
+    /// Lists versions of the manifesto text, oldest first
+    ManifestoVersions {
+        manifesto_id: u64,
+        start_after: Option<u32>,
+        limit: Option<u32>,
+    },
+    /// Returns the template of the traits of the MEDALs of a manifesto
+    MedalTemplate {
+        manifesto_id: u64,
+    },
+    /// Returns the MEDAL tiers of a manifesto with the number of MEDALs minted in each of them
+    MedalTiers {
+        manifesto_id: u64,
+    },
+    State {
+        manifesto_id: u64,
+    },
+    /// Returns the signing state of a manifesto as of the end of block `height`
+    StateAtHeight {
+        manifesto_id: u64,
+        height: u64,
+    },
+    GetSignature {
+        manifesto_id: u64,
+        signee: String,
+    },
+    SignatureByOrdinal {
+        manifesto_id: u64,
+        ordinal: u64,
+    },
+    /// Lists signatures of a manifesto signed on a Martian date, in signing order
+    SignaturesByDate {
+        manifesto_id: u64,
+        date: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Returns the number of signatures per sol between two Martian dates (inclusive, up to a
+    /// Darian year apart), and per Darian month overlapping them
+    SignatureHistogram {
+        manifesto_id: u64,
+        from: String,
+        to: String,
+    },
+    /// Checks if an address has an active signature
+    IsSignee {
+        manifesto_id: u64,
+        address: String,
+    },
+    /// Checks if each of the addresses has an active signature, invalid addresses being reported
+    /// as not signed
+    AreSignees {
+        manifesto_id: u64,
+        addresses: Vec<String>,
+    },
+    /// Checks if an address had an active signature as of the end of block `height`
+    SignatureAtHeight {
+        manifesto_id: u64,
+        signee: String,
+        height: u64,
+    },
+    /// Lists signatures of a manifesto in signing order
+    AllSignatures {
+        manifesto_id: u64,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+        order: Option<OrderBy>,
+    },
+    /// Returns the next nonce of relayed signatures of an address
+    Nonce {
+        address: String,
+    },
+    /// Returns the active and upcoming signing phases of a manifesto
+    Phases {
+        manifesto_id: u64,
+    },
+    /// Checks a Merkle proof of an address against the root of a signing round of a manifesto
+    /// (defaults to the current one)
+    IsEligible {
+        manifesto_id: u64,
+        address: String,
+        proof: Option<Vec<String>>,
+        round: Option<u32>,
+    },
+    /// Returns the guardian and the paused operations
+    Paused {},
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
-pub enum MedalExecuteMsg {
-    UpdateMedalRedeemConfig {
-        medal_redeem_addr: String,
-        metadata: MedalMetaData,
-    },
-    Mint(MintMsg),
+pub enum OrderBy {
+    Asc,
+    Desc,
 }
 
+/// `ExecuteMsg` of the MEDAL contract, whose tokens minted by the manifesto contract always
+/// carry metadata
+pub type MedalExecuteMsg = medal::ExecuteMsg<Metadata>;
+
+pub type MintMsg = medal::MintMsg<Metadata>;
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
-pub struct MintMsg {
-    /// Unique ID of the NFT
-    pub token_id: String,
-    /// The owner of the newly minter NFT
-    pub owner: String,
-    /// Identifies the asset to which this NFT represents
-    pub name: String,
-    /// Describes the asset to which this NFT represents (may be empty)
-    pub description: Option<String>,
-    /// A URI pointing to an image representing the asset
-    pub image: Option<String>,
-    /// Any custom extension used by this contract
-    pub extension: Metadata,
+pub struct ConfigResponse {
+    pub admin: String,
+    pub time_tolerance: u64,
+    pub manifestos_count: u64,
+    pub signing_fee: Option<SigningFee>,
+    /// True once signatures can no longer be imported
+    pub import_finalized: bool,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
-pub struct ConfigResponse {
-    pub medal_addr: Addr,
+pub struct AdminProposalResponse {
+    pub new_admin: Option<String>,
+    pub expires: Option<Expiration>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ManifestoResponse {
+    pub id: u64,
+    pub title: String,
+    /// Current version of the manifesto text
+    pub version: u32,
+    pub content_hash: String,
+    pub uri: Option<String>,
     pub max_signees_allowed: u64,
-    pub admin: String,
+    pub medal_addr: Addr,
+    pub medal_redeem_addr: Addr,
+    pub signee_count: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ManifestosResponse {
+    pub manifestos: Vec<ManifestoResponse>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ManifestoVersionResponse {
+    pub manifesto_id: u64,
+    pub version: u32,
+    pub content_hash: String,
+    pub uri: Option<String>,
+    pub published_at: Timestamp,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ManifestoVersionsResponse {
+    pub versions: Vec<ManifestoVersionResponse>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct StateResponse {
+    /// Number of signatures, including withdrawn ones
     pub signee_count: u64,
+    pub withdrawn_count: u64,
+    pub active_signee_count: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SignatureAtHeightResponse {
+    pub signee: Addr,
+    /// False if the address had not signed yet or had withdrawn its signature
+    pub signed: bool,
+    pub ordinal: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PhaseResponse {
+    pub phase: SigningPhase,
+    pub signees_count: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PhasesResponse {
+    pub current: Option<PhaseResponse>,
+    pub upcoming: Vec<PhaseResponse>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MedalTierResponse {
+    pub tier: MedalTier,
+    /// Number of MEDALs minted in the tier, counted by tier name
+    pub minted_count: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MedalTiersResponse {
+    pub tiers: Vec<MedalTierResponse>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct EligibilityResponse {
+    pub round: u32,
+    /// None if signing is open to everyone during the round
+    pub merkle_root: Option<String>,
+    pub is_eligible: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct NonceResponse {
+    pub nonce: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SignatureHistogramResponse {
+    /// Sols of the range with signatures, oldest first
+    pub sols: Vec<SolCount>,
+    /// Darian months overlapping the range with signatures, oldest first. Signatures of the
+    /// whole month are counted, including sols outside of the range
+    pub months: Vec<MonthCount>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SolCount {
+    pub martian_date: String,
+    /// Number of signatures, not counting withdrawn ones
+    pub count: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MonthCount {
+    pub year: u32,
+    pub month: String,
+    /// Number of signatures, not counting withdrawn ones
+    pub count: u64,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct SigneeResponse {
+    pub address: Addr,
+    /// False if the address has not signed or withdrew its signature
     pub is_signee: bool,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SigneesResponse {
+    /// One result per address, in the order of the query
+    pub signees: Vec<SigneeResponse>,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct SignatureResponse {
+    pub manifesto_id: u64,
     pub signee: String,
     pub martian_date: String,
     pub martian_time: String,
+    pub ordinal: u64,
+    /// None while the MEDAL mint is pending, see `ExecuteMsg::RetryMedalMint`
+    pub medal_token_id: Option<String>,
+    /// Version of the manifesto text the signee agreed to
+    pub version: u32,
+    pub block_height: u64,
+    pub signed_at: Timestamp,
+    pub withdrawn_at: Option<Timestamp>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SignaturesResponse {
+    pub signatures: Vec<SignatureResponse>,
 }
 
 /// Used when unwrapping an optional address sent in a contract call by a user.
@@ -116,3 +518,217 @@ pub struct MedalMetaData {
     pub image: String,
     pub token_uri: String,
 }
+
+/// Traits and OpenSea fields of the MEDALs of a manifesto, filled in when they are minted
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MedalTemplate {
+    pub traits: Vec<TraitTemplate>,
+    /// Hex color without the leading `#`, e.g. "c1440e"
+    pub background_color: Option<String>,
+    pub animation_url: Option<String>,
+}
+
+/// Trait holding the Martian date of a MEDAL, from which its SVG image is rendered
+pub const MARTIAN_DATE_TRAIT: &str = "martian_date";
+/// Trait holding the Martian time of a MEDAL, from which its SVG image is rendered
+pub const MARTIAN_TIME_TRAIT: &str = "martian_time";
+
+impl MedalTemplate {
+    /// Returns the first of the traits required to render the SVG image of a MEDAL that the
+    /// template drops or fills with another value, if any
+    pub fn missing_svg_trait(&self) -> Option<&'static str> {
+        [
+            (MARTIAN_DATE_TRAIT, TraitPlaceholder::MartianDate),
+            (MARTIAN_TIME_TRAIT, TraitPlaceholder::MartianTime),
+        ]
+        .iter()
+        .find(|(trait_type, placeholder)| {
+            !self.traits.iter().any(|trait_| {
+                trait_.trait_type == *trait_type
+                    && trait_.value == TraitValue::Placeholder(*placeholder)
+            })
+        })
+        .map(|(trait_type, _)| *trait_type)
+    }
+}
+
+impl Default for MedalTemplate {
+    fn default() -> Self {
+        let placeholder = |trait_type: &str, placeholder| TraitTemplate {
+            trait_type: trait_type.to_string(),
+            display_type: None,
+            value: TraitValue::Placeholder(placeholder),
+        };
+        MedalTemplate {
+            traits: vec![
+                placeholder(MARTIAN_DATE_TRAIT, TraitPlaceholder::MartianDate),
+                placeholder(MARTIAN_TIME_TRAIT, TraitPlaceholder::MartianTime),
+            ],
+            background_color: None,
+            animation_url: None,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TraitTemplate {
+    pub trait_type: String,
+    pub display_type: Option<DisplayType>,
+    pub value: TraitValue,
+}
+
+/// How OpenSea displays a trait
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DisplayType {
+    Number,
+    BoostNumber,
+    BoostPercentage,
+    /// The value is a Unix timestamp in seconds
+    Date,
+}
+
+impl fmt::Display for DisplayType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let display_type = match self {
+            DisplayType::Number => "number",
+            DisplayType::BoostNumber => "boost_number",
+            DisplayType::BoostPercentage => "boost_percentage",
+            DisplayType::Date => "date",
+        };
+        write!(f, "{}", display_type)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TraitValue {
+    /// Same value for every MEDAL
+    Static(String),
+    /// Value taken from the signature
+    Placeholder(TraitPlaceholder),
+}
+
+/// Range of ordinals whose MEDALs have their own name, description and art, e.g. a
+/// "Founding Martian" tier for the first 100 signees
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MedalTier {
+    /// Unique name, set as the `tier` trait of its MEDALs
+    pub name: String,
+    /// Tier covers ordinals from `first_ordinal` ...
+    pub first_ordinal: u64,
+    /// ... to `last_ordinal` (inclusive)
+    pub last_ordinal: u64,
+    pub metadata: MedalMetaData,
+}
+
+impl MedalTier {
+    /// Returns true if the tier covers the given ordinal
+    pub fn contains(&self, ordinal: u64) -> bool {
+        self.first_ordinal <= ordinal && ordinal <= self.last_ordinal
+    }
+}
+
+/// Detail of a signature filled in a trait
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TraitPlaceholder {
+    /// Signing order, starting at 1
+    Ordinal,
+    BlockHeight,
+    /// Unix timestamp in seconds, to display with `DisplayType::Date`
+    EarthDate,
+    /// e.g. "26 Taurus 219"
+    MartianDate,
+    /// e.g. "09:05:33 MTC"
+    MartianTime,
+    /// Name of the month of the Darian calendar, e.g. "Taurus"
+    DarianMonth,
+    /// Id of the signing phase during which the manifesto was signed, 0 if there are no phases
+    SigningPhase,
+}
+
+/// Fee charged to new signees, to cover MEDAL production costs
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SigningFee {
+    /// Address the fees are forwarded to
+    pub treasury: Addr,
+    /// Accepted denoms, with the amount to pay in each of them
+    pub amounts: Vec<Coin>,
+}
+
+/// Fee charged by the MEDAL contract to ship the physical pin of a redeemed MEDAL to `region`
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RegionFee {
+    pub region: String,
+    /// Accepted denoms, with the amount to pay in each of them. Shipping is free if empty
+    pub amounts: Vec<Coin>,
+}
+
+/// A window during which the Manifesto can be signed, with its own signee cap
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SigningPhase {
+    /// Unique identifier, used to track the number of signees of the phase
+    pub id: u32,
+    /// Phase is open from `start` (inclusive) ...
+    pub start: PhaseBoundary,
+    /// ... until `end` (exclusive)
+    pub end: PhaseBoundary,
+    pub max_signees: u64,
+}
+
+impl SigningPhase {
+    /// Returns true if the phase is open at the given block
+    pub fn is_active(&self, block: &BlockInfo) -> bool {
+        self.start.is_reached(block) && !self.end.is_reached(block)
+    }
+
+    /// Returns false if the phase ends before or when it starts. A height can't be compared
+    /// with a time or Martian date, such windows are assumed valid
+    pub fn has_valid_window(&self) -> bool {
+        match (&self.start, &self.end) {
+            (PhaseBoundary::AtHeight(start), PhaseBoundary::AtHeight(end)) => start < end,
+            (PhaseBoundary::AtHeight(_), _) | (_, PhaseBoundary::AtHeight(_)) => true,
+            (start, end) => start.time() < end.time(),
+        }
+    }
+}
+
+/// Start or end of a signing phase
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PhaseBoundary {
+    AtHeight(u64),
+    AtTime(Timestamp),
+    /// Start of a sol of the Darian calendar
+    AtMartianDate(MartianDate),
+}
+
+impl PhaseBoundary {
+    /// Returns true once the block has reached the boundary
+    pub fn is_reached(&self, block: &BlockInfo) -> bool {
+        match self {
+            PhaseBoundary::AtHeight(height) => block.height >= *height,
+            PhaseBoundary::AtTime(time) => block.time >= *time,
+            PhaseBoundary::AtMartianDate(date) => {
+                mars_sol_date(block.time) >= date.to_mars_sol_date()
+            }
+        }
+    }
+
+    /// Returns the earliest time at which the boundary is reached, or None for heights
+    fn time(&self) -> Option<Timestamp> {
+        match self {
+            PhaseBoundary::AtHeight(_) => None,
+            PhaseBoundary::AtTime(time) => Some(*time),
+            PhaseBoundary::AtMartianDate(date) => {
+                let midnight = MartianTime::new(0, 0, 0).unwrap();
+                Some(match to_timestamp(date, &midnight) {
+                    Ok(time) => time,
+                    Err(DarianError::BeforeUnixEpoch {}) => Timestamp::from_nanos(0),
+                    Err(_) => Timestamp::from_nanos(u64::MAX),
+                })
+            }
+        }
+    }
+}
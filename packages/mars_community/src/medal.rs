@@ -0,0 +1,91 @@
+//! Messages of the MEDAL contract, shared with the contracts calling it
+use cosmwasm_std::Binary;
+use cw721::Expiration;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::manifesto::{MedalMetaData, RegionFee};
+use crate::pause::PauseFlag;
+
+/// This is like Cw721ExecuteMsg but we add a Mint command for an owner
+/// to make this stand-alone. You will likely want to remove mint and
+/// use other control logic in any contract that inherits this.
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg<T> {
+    /// Admin function to set the MEDAL (Redeem) Contract address
+    UpdateMedalRedeemConfig {
+        medal_redeem_addr: String,
+        metadata: MedalMetaData,
+    },
+    /// Transfer is a base message to move a token to another account without triggering actions
+    TransferNft { recipient: String, token_id: String },
+    /// Send is a base message to transfer a token to a contract and trigger an action
+    /// on the receiving contract.
+    SendNft {
+        contract: String,
+        token_id: String,
+        msg: Binary,
+    },
+    /// Allows operator to transfer / send the token from the owner's account.
+    /// If expiration is set, then this allowance has a time/height limit
+    Approve {
+        spender: String,
+        token_id: String,
+        expires: Option<Expiration>,
+    },
+    /// Remove previously granted Approval
+    Revoke { spender: String, token_id: String },
+    /// Allows operator to transfer / send any token from the owner's account.
+    /// If expiration is set, then this allowance has a time/height limit
+    ApproveAll {
+        operator: String,
+        expires: Option<Expiration>,
+    },
+    /// Remove previously granted ApproveAll permission
+    RevokeAll { operator: String },
+    /// Mint a new NFT, can only be called by the contract minter
+    Mint(MintMsg<T>),
+    /// Sets (or removes, if None) the guardian, who may pause operations but not unpause them.
+    /// Can only be called by the admin
+    UpdateGuardian { guardian: Option<String> },
+    /// Pauses operations, can be called by the guardian or the admin
+    Pause { flags: Vec<PauseFlag> },
+    /// Lifts the pause of operations, can only be called by the admin
+    Unpause { flags: Vec<PauseFlag> },
+    /// Burns a MEDAL and mints an R-MEDAL for it. If there is a redemption fee, `region` is
+    /// required and the fee of the region must be sent, in a single accepted denom. Overpayment
+    /// is refunded
+    RedeemMedal {
+        token_id: String,
+        region: Option<String>,
+    },
+    /// Sets (or removes, if None) the SVG template the `image_data` of tokens is rendered from.
+    /// Can only be called by the contract minter
+    UpdateSvgTemplate { template: Option<String> },
+    /// Sets the redemption fee, forwarded to `recipient`. Redemption is free if `regions` is
+    /// empty. Can only be called by the contract minter
+    UpdateRedemptionFee {
+        recipient: String,
+        regions: Vec<RegionFee>,
+    },
+    /// Burns the MEDAL of a signee withdrawing their signature, can only be called by the
+    /// contract minter. Does nothing if the token was redeemed or no longer belongs to `owner`
+    BurnMedal { token_id: String, owner: String },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MintMsg<T> {
+    /// Unique ID of the NFT
+    pub token_id: String,
+    /// The owner of the newly minter NFT
+    pub owner: String,
+    /// Identifies the asset to which this NFT represents
+    pub name: String,
+    /// Describes the asset to which this NFT represents (may be empty)
+    pub description: Option<String>,
+    /// A URI pointing to an image representing the asset
+    pub image: Option<String>,
+    /// Any custom extension used by this contract
+    pub extension: T,
+}
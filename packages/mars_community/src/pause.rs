@@ -0,0 +1,170 @@
+use std::fmt;
+
+use cosmwasm_std::{Addr, StdError, StdResult, Storage};
+use cw_storage_plus::Item;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Guardian allowed to pause operations besides the admin
+pub const GUARDIAN: Item<Addr> = Item::new("pause_guardian");
+/// Operations currently paused
+pub const PAUSED: Item<PauseFlags> = Item::new("paused");
+
+/// Operation which can be paused
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PauseFlag {
+    /// Signing a manifesto
+    Signing,
+    /// Minting MEDALs and R-MEDALs
+    Minting,
+    /// Transferring and sending tokens
+    Transfers,
+    /// Redeeming MEDALs for physical pins
+    Redemption,
+}
+
+impl fmt::Display for PauseFlag {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let flag = match self {
+            PauseFlag::Signing => "signing",
+            PauseFlag::Minting => "minting",
+            PauseFlag::Transfers => "transfers",
+            PauseFlag::Redemption => "redemption",
+        };
+        write!(f, "{}", flag)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+pub struct PauseFlags {
+    pub signing: bool,
+    pub minting: bool,
+    pub transfers: bool,
+    pub redemption: bool,
+}
+
+impl PauseFlags {
+    pub fn is_paused(&self, flag: PauseFlag) -> bool {
+        match flag {
+            PauseFlag::Signing => self.signing,
+            PauseFlag::Minting => self.minting,
+            PauseFlag::Transfers => self.transfers,
+            PauseFlag::Redemption => self.redemption,
+        }
+    }
+
+    fn set(&mut self, flag: PauseFlag, paused: bool) {
+        match flag {
+            PauseFlag::Signing => self.signing = paused,
+            PauseFlag::Minting => self.minting = paused,
+            PauseFlag::Transfers => self.transfers = paused,
+            PauseFlag::Redemption => self.redemption = paused,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PausedResponse {
+    pub guardian: Option<Addr>,
+    pub paused: PauseFlags,
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum PauseError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("{flag} is paused")]
+    Paused { flag: PauseFlag },
+}
+
+/// Sets (or removes) the guardian, can only be called by the admin
+pub fn update_guardian(
+    storage: &mut dyn Storage,
+    sender: &Addr,
+    admin: &Addr,
+    guardian: Option<Addr>,
+) -> Result<(), PauseError> {
+    if sender != admin {
+        return Err(PauseError::Unauthorized {});
+    }
+
+    match guardian {
+        Some(guardian) => GUARDIAN.save(storage, &guardian)?,
+        None => GUARDIAN.remove(storage),
+    }
+    Ok(())
+}
+
+/// Pauses the given operations, can be called by the guardian or the admin
+pub fn pause(
+    storage: &mut dyn Storage,
+    sender: &Addr,
+    admin: &Addr,
+    flags: &[PauseFlag],
+) -> Result<PauseFlags, PauseError> {
+    let guardian = GUARDIAN.may_load(storage)?;
+    if sender != admin && guardian.as_ref() != Some(sender) {
+        return Err(PauseError::Unauthorized {});
+    }
+
+    set_flags(storage, flags, true)
+}
+
+/// Lifts the pause of the given operations, can only be called by the admin
+pub fn unpause(
+    storage: &mut dyn Storage,
+    sender: &Addr,
+    admin: &Addr,
+    flags: &[PauseFlag],
+) -> Result<PauseFlags, PauseError> {
+    if sender != admin {
+        return Err(PauseError::Unauthorized {});
+    }
+
+    set_flags(storage, flags, false)
+}
+
+/// Returns an error if `flag` is paused
+pub fn assert_not_paused(storage: &dyn Storage, flag: PauseFlag) -> Result<(), PauseError> {
+    let paused = PAUSED.may_load(storage)?.unwrap_or_default();
+    if paused.is_paused(flag) {
+        return Err(PauseError::Paused { flag });
+    }
+    Ok(())
+}
+
+/// Returns the guardian and the paused operations
+pub fn query_paused(storage: &dyn Storage) -> StdResult<PausedResponse> {
+    Ok(PausedResponse {
+        guardian: GUARDIAN.may_load(storage)?,
+        paused: PAUSED.may_load(storage)?.unwrap_or_default(),
+    })
+}
+
+/// Joins `flags` for response attributes
+pub fn flags_to_string(flags: &[PauseFlag]) -> String {
+    flags
+        .iter()
+        .map(|flag| flag.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn set_flags(
+    storage: &mut dyn Storage,
+    flags: &[PauseFlag],
+    paused: bool,
+) -> Result<PauseFlags, PauseError> {
+    let mut pause_flags = PAUSED.may_load(storage)?.unwrap_or_default();
+    for flag in flags {
+        pause_flags.set(*flag, paused);
+    }
+    PAUSED.save(storage, &pause_flags)?;
+    Ok(pause_flags)
+}
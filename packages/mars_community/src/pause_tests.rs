@@ -0,0 +1,121 @@
+#![cfg(test)]
+use cosmwasm_std::testing::MockStorage;
+use cosmwasm_std::Addr;
+
+use crate::pause::{
+    assert_not_paused, flags_to_string, pause, query_paused, unpause, update_guardian, PauseError,
+    PauseFlag, PauseFlags, PausedResponse,
+};
+
+#[test]
+fn guardian() {
+    let mut storage = MockStorage::new();
+    let admin = Addr::unchecked("admin");
+    let guardian = Addr::unchecked("guardian");
+
+    // only the admin sets the guardian
+    let err = update_guardian(&mut storage, &guardian, &admin, Some(guardian.clone())).unwrap_err();
+    assert_eq!(err, PauseError::Unauthorized {});
+    update_guardian(&mut storage, &admin, &admin, Some(guardian.clone())).unwrap();
+    assert_eq!(
+        query_paused(&storage).unwrap(),
+        PausedResponse {
+            guardian: Some(guardian.clone()),
+            paused: PauseFlags::default(),
+        }
+    );
+
+    update_guardian(&mut storage, &admin, &admin, None).unwrap();
+    assert_eq!(None, query_paused(&storage).unwrap().guardian);
+    let err = pause(&mut storage, &guardian, &admin, &[PauseFlag::Minting]).unwrap_err();
+    assert_eq!(err, PauseError::Unauthorized {});
+}
+
+#[test]
+fn pausing() {
+    let mut storage = MockStorage::new();
+    let admin = Addr::unchecked("admin");
+    let guardian = Addr::unchecked("guardian");
+    update_guardian(&mut storage, &admin, &admin, Some(guardian.clone())).unwrap();
+
+    // anyone else can't pause
+    let err = pause(
+        &mut storage,
+        &Addr::unchecked("mallory"),
+        &admin,
+        &[PauseFlag::Signing],
+    )
+    .unwrap_err();
+    assert_eq!(err, PauseError::Unauthorized {});
+
+    // the guardian can pause, but not unpause
+    let flags = pause(
+        &mut storage,
+        &guardian,
+        &admin,
+        &[PauseFlag::Signing, PauseFlag::Transfers],
+    )
+    .unwrap();
+    assert_eq!(
+        flags,
+        PauseFlags {
+            signing: true,
+            minting: false,
+            transfers: true,
+            redemption: false,
+        }
+    );
+    let err = unpause(&mut storage, &guardian, &admin, &[PauseFlag::Signing]).unwrap_err();
+    assert_eq!(err, PauseError::Unauthorized {});
+
+    // the admin can pause and unpause
+    pause(&mut storage, &admin, &admin, &[PauseFlag::Minting]).unwrap();
+    let flags = unpause(&mut storage, &admin, &admin, &[PauseFlag::Signing]).unwrap();
+    assert_eq!(
+        flags,
+        PauseFlags {
+            signing: false,
+            minting: true,
+            transfers: true,
+            redemption: false,
+        }
+    );
+    assert_eq!(flags, query_paused(&storage).unwrap().paused);
+}
+
+#[test]
+fn checking_flags() {
+    let mut storage = MockStorage::new();
+    let admin = Addr::unchecked("admin");
+
+    // nothing is paused before the first pause
+    assert_not_paused(&storage, PauseFlag::Redemption).unwrap();
+
+    pause(&mut storage, &admin, &admin, &[PauseFlag::Redemption]).unwrap();
+    let err = assert_not_paused(&storage, PauseFlag::Redemption).unwrap_err();
+    assert_eq!(
+        err,
+        PauseError::Paused {
+            flag: PauseFlag::Redemption
+        }
+    );
+    assert_eq!("redemption is paused", err.to_string());
+    assert_not_paused(&storage, PauseFlag::Minting).unwrap();
+
+    unpause(&mut storage, &admin, &admin, &[PauseFlag::Redemption]).unwrap();
+    assert_not_paused(&storage, PauseFlag::Redemption).unwrap();
+}
+
+#[test]
+fn flags_as_attributes() {
+    assert_eq!("", flags_to_string(&[]));
+    assert_eq!(
+        "signing,minting,transfers,redemption",
+        flags_to_string(&[
+            PauseFlag::Signing,
+            PauseFlag::Minting,
+            PauseFlag::Transfers,
+            PauseFlag::Redemption,
+        ])
+    );
+}
@@ -0,0 +1,35 @@
+use cosmwasm_std::{Coin, QuerierWrapper, StdResult, Uint128};
+use terra_cosmwasm::TerraQuerier;
+
+/// Denom exempted from the stability tax
+const LUNA_DENOM: &str = "uluna";
+const DECIMAL_FRACTION: Uint128 = Uint128::new(1_000_000_000_000_000_000u128);
+
+/// Returns `coin` minus its stability tax, i.e. the coin to send so that sending it and paying
+/// its tax costs exactly `coin`
+pub fn deduct_tax(querier: &QuerierWrapper, coin: Coin) -> StdResult<Coin> {
+    let tax_amount = compute_tax(querier, &coin)?;
+    Ok(Coin {
+        amount: coin.amount.checked_sub(tax_amount)?,
+        denom: coin.denom,
+    })
+}
+
+/// Computes the stability tax included in `coin`: `amount * rate / (1 + rate)`, capped by the
+/// tax cap of its denom
+pub fn compute_tax(querier: &QuerierWrapper, coin: &Coin) -> StdResult<Uint128> {
+    if coin.denom == LUNA_DENOM {
+        return Ok(Uint128::zero());
+    }
+
+    let terra_querier = TerraQuerier::new(querier);
+    let tax_rate = terra_querier.query_tax_rate()?.rate;
+    let tax_cap = terra_querier.query_tax_cap(coin.denom.clone())?.cap;
+
+    let amount_before_tax = coin.amount.multiply_ratio(
+        DECIMAL_FRACTION,
+        DECIMAL_FRACTION * tax_rate + DECIMAL_FRACTION,
+    );
+    let tax_amount = coin.amount.checked_sub(amount_before_tax)?;
+    Ok(std::cmp::min(tax_amount, tax_cap))
+}
@@ -0,0 +1,67 @@
+#![cfg(test)]
+use cosmwasm_std::testing::MockQuerier;
+use cosmwasm_std::{coin, to_binary, ContractResult, Decimal, QuerierWrapper, SystemResult};
+use terra_cosmwasm::{TaxCapResponse, TaxRateResponse, TerraQuery, TerraQueryWrapper};
+
+use crate::tax::{compute_tax, deduct_tax};
+
+/// Querier answering Terra tax queries with a 0.1% rate and a cap of `tax_cap`
+fn mock_querier(tax_cap: u128) -> MockQuerier<TerraQueryWrapper> {
+    MockQuerier::new(&[]).with_custom_handler(move |query: &TerraQueryWrapper| {
+        let res = match &query.query_data {
+            TerraQuery::TaxRate {} => to_binary(&TaxRateResponse {
+                rate: Decimal::permille(1),
+            }),
+            TerraQuery::TaxCap { .. } => to_binary(&TaxCapResponse {
+                cap: tax_cap.into(),
+            }),
+            q => panic!("unexpected query: {:?}", q),
+        };
+        SystemResult::Ok(ContractResult::Ok(res.unwrap()))
+    })
+}
+
+#[test]
+fn computing_tax() {
+    let querier = mock_querier(1_000_000);
+    let querier = QuerierWrapper::new(&querier);
+
+    // the tax is included in the amount: 1_001_000 = 1_000_000 + 0.1%
+    assert_eq!(
+        1_000u128,
+        compute_tax(&querier, &coin(1_001_000, "uusd"))
+            .unwrap()
+            .u128()
+    );
+    assert_eq!(
+        coin(1_000_000, "uusd"),
+        deduct_tax(&querier, coin(1_001_000, "uusd")).unwrap()
+    );
+    assert_eq!(
+        coin(0, "uusd"),
+        deduct_tax(&querier, coin(0, "uusd")).unwrap()
+    );
+
+    // luna is not taxed
+    assert_eq!(
+        coin(1_001_000, "uluna"),
+        deduct_tax(&querier, coin(1_001_000, "uluna")).unwrap()
+    );
+}
+
+#[test]
+fn capping_tax() {
+    let querier = mock_querier(500);
+    let querier = QuerierWrapper::new(&querier);
+
+    assert_eq!(
+        500u128,
+        compute_tax(&querier, &coin(1_001_000, "uusd"))
+            .unwrap()
+            .u128()
+    );
+    assert_eq!(
+        coin(1_000_500, "uusd"),
+        deduct_tax(&querier, coin(1_001_000, "uusd")).unwrap()
+    );
+}
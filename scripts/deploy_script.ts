@@ -7,7 +7,7 @@ import {
   instantiateContract,
   uploadContract
 } from "./helpers.js"
-import {manifesto_medal_config, manifesto_medal_redeem_config, sign_manifesto, get_config, get_state, get_signature} from "./manifesto_utils.js";
+import {register_manifesto, manifesto_medal_config, manifesto_medal_redeem_config, sign_manifesto, get_config, get_state, get_signature} from "./manifesto_utils.js";
 import {redeem_medal, transfer_nft} from "./medal_utils.js";
 import { LCDClient } from "@terra-money/terra.js"
 
@@ -20,6 +20,10 @@ const MEDAL_TOKEN_URI = "QmSmFEyiNqZGz8HNnhf5sYxZXHSR8DLLDJ7xp2PM6sxrzG"
 const MEDAL_REDEEMED_CID = "QmbbXKezWaxC687a3jQffzyc8u8xSyLv3aTGsNzdzk393y"
 const MEDAL_REDEEMED_TOKEN_URI = "QmSiaaZDNJAuuNX34nFWbxusesgS3Aq1NdCUC8gi6wo2Ks"
 
+// Hex encoded SHA-256 hash of the Mars Manifesto text
+const MANIFESTO_CONTENT_HASH = process.env.MANIFESTO_CONTENT_HASH!
+const MANIFESTO_URI = "https://manifesto.marsprotocol.io/"
+
 
 async function main() {
 
@@ -38,14 +42,15 @@ async function main() {
     let manifesto_id = await uploadContract(terra, deployer, '../artifacts/manifesto.wasm');
     console.log('MANIFESTO CONTRACT ID : ' + manifesto_id )
     let manifesto_init_msg = { 
-      medal_addr: null,
-      medal_redeem_addr: null,
-      max_signees_limit: 1100,
-      admin: deployer.key.accAddress
+      admin: deployer.key.accAddress,
+      time_tolerance: 300
      }
     let manifesto_address = await instantiateContract(terra, deployer, manifesto_id, manifesto_init_msg, "MARS MANIFESTO" );
     console.log('MANIFESTO ADDRESS : ' + manifesto_address )
 
+    await register_manifesto( terra, deployer, manifesto_address, "Mars Manifesto", MANIFESTO_CONTENT_HASH, MANIFESTO_URI, 1100);
+    let mars_manifesto_id = 1;
+
     // #################################################    
     // #########     MEDAL :::  DEPLOYMENT     #########
     // #################################################    
@@ -55,7 +60,8 @@ async function main() {
     let medal_init_msg = { 
       name: "MEDAL",
       symbol: "MEDAL",
-      minter: manifesto_address
+      minter: manifesto_address,
+      admin: deployer.key.accAddress
      };
     let medal_address = await instantiateContract(terra, deployer, medal_id, medal_init_msg, "MEDAL" );
     console.log('MEDAL ADDRESS : ' + medal_address )
@@ -69,7 +75,8 @@ async function main() {
     let medal_redeemed_init_msg = { 
       name: "R-MEDAL",
       symbol: "RMEDAL",
-      minter: medal_address
+      minter: medal_address,
+      admin: deployer.key.accAddress
      };
     let medal_redeemed_address = await instantiateContract(terra, deployer, medal_redeemed_id, medal_redeemed_init_msg, "R-MEDAL" );
     console.log('MEDAL (REDEEM) ADDRESS : ' + medal_redeemed_address )
@@ -92,7 +99,7 @@ async function main() {
                           }; 
                           
                     
-    await manifesto_medal_config( terra, deployer, manifesto_address, medal_address, medal_metadata);
+    await manifesto_medal_config( terra, deployer, manifesto_address, mars_manifesto_id, medal_address, medal_metadata);
     console.log('SUCCESSFULLY UPDATED ')
 
 
@@ -106,7 +113,7 @@ async function main() {
                                    "image": "ipfs://" + MEDAL_REDEEMED_CID,
                                    "token_uri": "ipfs://" + MEDAL_REDEEMED_TOKEN_URI
                                   };
-    await manifesto_medal_redeem_config( terra, deployer, manifesto_address, medal_redeemed_address, medal_redeem_metadata);
+    await manifesto_medal_redeem_config( terra, deployer, manifesto_address, mars_manifesto_id, medal_redeemed_address, medal_redeem_metadata);
     console.log('SUCCESSFULLY UPDATED ')
 
 }
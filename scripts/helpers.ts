@@ -99,8 +99,8 @@ export async function deployContract( terra: LocalTerra | LCDClient, wallet: Wal
   return await instantiateContract(terra, wallet, codeId, initMsg, memo);
 }
 
-export async function migrate( terra: LocalTerra | LCDClient, wallet: Wallet, contractAddress: string, newCodeId: number) {
-  const migrateMsg = new MsgMigrateContract(wallet.key.accAddress, contractAddress, newCodeId, {});
+export async function migrate( terra: LocalTerra | LCDClient, wallet: Wallet, contractAddress: string, newCodeId: number, msg: object = {}) {
+  const migrateMsg = new MsgMigrateContract(wallet.key.accAddress, contractAddress, newCodeId, msg);
   return await performTransaction(terra, wallet, migrateMsg);
 }
 
@@ -8,50 +8,163 @@ import { executeContract, queryContract} from "./helpers.js";
 //-----------------------------------------------------
 
 // ------ ExecuteContract :: Function signatures ------
-// - update_admin
+// - propose_new_admin
+// - claim_admin
+// - drop_admin_proposal
+// - register_manifesto
+// - amend_manifesto
 // - update_medal_config
 // - update_medal_redeem_config
+// - update_medal_template
+// - update_medal_tiers
+// - update_medal_svg_template
+// - update_medal_redemption_fee
+// - update_signing_fee
 // - sign_manifesto
+// - withdraw_signature
+// - retry_medal_mint
+// - import_signatures
+// - finalize_import
+// - migrate_legacy_signatures
 //------------------------------------------------------
 //------------------------------------------------------
 // ----------- Queries :: Function signatures ----------
 // - config
+// - manifesto
+// - manifesto_version
 // - state
 // - get_signature
 //------------------------------------------------------
 
 
 
-export async function manifesto_update_config(terra: LocalTerra | LCDClient, wallet: Wallet, manifesto_address: string, new_admin: string) {
-  let _msg = { "update_admin": { "new_admin":new_admin } };
+export async function manifesto_propose_new_admin(terra: LocalTerra | LCDClient, wallet: Wallet, manifesto_address: string, new_admin: string, expires: object) {
+  let _msg = { "propose_new_admin": { "new_admin":new_admin, "expires":expires } };
   let resp = await executeContract(terra, wallet, manifesto_address, _msg ); 
   return resp;
 }
 
 
-export async function manifesto_medal_config(terra: LocalTerra | LCDClient, wallet: Wallet, manifesto_address: string, medal_addr: string, metadata: object) {
-  let _msg = { "update_medal_config": { "medal_addr":medal_addr, "metadata":metadata } };
+export async function manifesto_claim_admin(terra: LocalTerra | LCDClient, wallet: Wallet, manifesto_address: string) {
+  let _msg = { "claim_admin": {} };
   let resp = await executeContract(terra, wallet, manifesto_address, _msg ); 
   return resp;
 }
 
 
-export async function manifesto_medal_redeem_config(terra: LocalTerra | LCDClient, wallet: Wallet, manifesto_address: string, medal_redeem_addr: string, metadata: object) {
-  let _msg = { "update_medal_redeem_config": { "medal_redeem_addr":medal_redeem_addr, "metadata":metadata } };
+export async function manifesto_drop_admin_proposal(terra: LocalTerra | LCDClient, wallet: Wallet, manifesto_address: string) {
+  let _msg = { "drop_admin_proposal": {} };
   let resp = await executeContract(terra, wallet, manifesto_address, _msg ); 
   return resp;
 }
 
 
-  export async function sign_manifesto(terra: LocalTerra | LCDClient, wallet: Wallet, manifesto_address: string, martian_date: string, martian_time: string) {
-    let sign_msg = { "sign_manifesto": { "martian_date":martian_date, "martian_time":martian_time } };
-    let resp = await executeContract(terra, wallet, manifesto_address, sign_msg ); 
+export async function register_manifesto(terra: LocalTerra | LCDClient, wallet: Wallet, manifesto_address: string, title: string, content_hash: string, uri: string | null, max_signees_limit: number) {
+  let _msg = { "register_manifesto": { "title":title, "content_hash":content_hash, "uri":uri, "max_signees_limit":max_signees_limit } };
+  let resp = await executeContract(terra, wallet, manifesto_address, _msg ); 
+  return resp;
+}
+
+
+export async function amend_manifesto(terra: LocalTerra | LCDClient, wallet: Wallet, manifesto_address: string, manifesto_id: number, content_hash: string, uri: string | null) {
+  let _msg = { "amend_manifesto": { "manifesto_id":manifesto_id, "content_hash":content_hash, "uri":uri } };
+  let resp = await executeContract(terra, wallet, manifesto_address, _msg ); 
+  return resp;
+}
+
+
+export async function manifesto_medal_config(terra: LocalTerra | LCDClient, wallet: Wallet, manifesto_address: string, manifesto_id: number, medal_addr: string, metadata: object) {
+  let _msg = { "update_medal_config": { "manifesto_id":manifesto_id, "medal_addr":medal_addr, "metadata":metadata } };
+  let resp = await executeContract(terra, wallet, manifesto_address, _msg ); 
+  return resp;
+}
+
+
+export async function manifesto_update_medal_template(terra: LocalTerra | LCDClient, wallet: Wallet, manifesto_address: string, manifesto_id: number, template?: object) {
+  let _msg = { "update_medal_template": { "manifesto_id":manifesto_id, "template":template } };
+  let resp = await executeContract(terra, wallet, manifesto_address, _msg ); 
+  return resp;
+}
+
+
+export async function manifesto_update_medal_tiers(terra: LocalTerra | LCDClient, wallet: Wallet, manifesto_address: string, manifesto_id: number, tiers: Array<object>) {
+  let _msg = { "update_medal_tiers": { "manifesto_id":manifesto_id, "tiers":tiers } };
+  let resp = await executeContract(terra, wallet, manifesto_address, _msg ); 
+  return resp;
+}
+
+
+export async function manifesto_update_medal_svg_template(terra: LocalTerra | LCDClient, wallet: Wallet, manifesto_address: string, manifesto_id: number, template?: string) {
+  let _msg = { "update_medal_svg_template": { "manifesto_id":manifesto_id, "template":template } };
+  let resp = await executeContract(terra, wallet, manifesto_address, _msg ); 
+  return resp;
+}
+
+export async function manifesto_update_medal_redemption_fee(terra: LocalTerra | LCDClient, wallet: Wallet, manifesto_address: string, manifesto_id: number, recipient: string, regions: object[]) {
+  let _msg = { "update_medal_redemption_fee": { "manifesto_id":manifesto_id, "recipient":recipient, "regions":regions } };
+  let resp = await executeContract(terra, wallet, manifesto_address, _msg ); 
+  return resp;
+}
+
+
+export async function manifesto_update_signing_fee(terra: LocalTerra | LCDClient, wallet: Wallet, manifesto_address: string, treasury: string, amounts: Array<object>) {
+  let _msg = { "update_signing_fee": { "treasury":treasury, "amounts":amounts } };
+  let resp = await executeContract(terra, wallet, manifesto_address, _msg ); 
+  return resp;
+}
+
+
+export async function manifesto_medal_redeem_config(terra: LocalTerra | LCDClient, wallet: Wallet, manifesto_address: string, manifesto_id: number, medal_redeem_addr: string, metadata: object) {
+  let _msg = { "update_medal_redeem_config": { "manifesto_id":manifesto_id, "medal_redeem_addr":medal_redeem_addr, "metadata":metadata } };
+  let resp = await executeContract(terra, wallet, manifesto_address, _msg ); 
+  return resp;
+}
+
+
+  export async function sign_manifesto(terra: LocalTerra | LCDClient, wallet: Wallet, manifesto_address: string, manifesto_id: number, content_hash: string, martian_date: string, martian_time: string, signing_fee?: any) {
+    let sign_msg = { "sign_manifesto": { "manifesto_id":manifesto_id, "content_hash":content_hash, "martian_date":martian_date, "martian_time":martian_time } };
+    let resp = await executeContract(terra, wallet, manifesto_address, sign_msg, signing_fee ); 
+    return resp;
+  }
+
+
+
+
+
+
+  export async function withdraw_signature(terra: LocalTerra | LCDClient, wallet: Wallet, manifesto_address: string, manifesto_id: number) {
+    let withdraw_msg = { "withdraw_signature": { "manifesto_id":manifesto_id } };
+    let resp = await executeContract(terra, wallet, manifesto_address, withdraw_msg ); 
+    return resp;
+  }
+
+
+  export async function retry_medal_mint(terra: LocalTerra | LCDClient, wallet: Wallet, manifesto_address: string, manifesto_id: number) {
+    let retry_msg = { "retry_medal_mint": { "manifesto_id":manifesto_id } };
+    let resp = await executeContract(terra, wallet, manifesto_address, retry_msg ); 
+    return resp;
+  }
+
+
+  export async function import_signatures(terra: LocalTerra | LCDClient, wallet: Wallet, manifesto_address: string, manifesto_id: number, entries: Array<object>, mint_medals: boolean) {
+    let import_msg = { "import_signatures": { "manifesto_id":manifesto_id, "entries":entries, "mint_medals":mint_medals } };
+    let resp = await executeContract(terra, wallet, manifesto_address, import_msg ); 
     return resp;
   }
 
 
+  export async function finalize_import(terra: LocalTerra | LCDClient, wallet: Wallet, manifesto_address: string) {
+    let finalize_msg = { "finalize_import": {} };
+    let resp = await executeContract(terra, wallet, manifesto_address, finalize_msg ); 
+    return resp;
+  }
 
 
+  export async function migrate_legacy_signatures(terra: LocalTerra | LCDClient, wallet: Wallet, manifesto_address: string, entries: { signee: string, token_id: string }[]) {
+    let migrate_msg = { "migrate_legacy_signatures": { "entries":entries } };
+    let resp = await executeContract(terra, wallet, manifesto_address, migrate_msg ); 
+    return resp;
+  }
 
 
   export async function get_config(terra: LocalTerra | LCDClient, manifesto_address: string) {
@@ -60,14 +173,62 @@ export async function manifesto_medal_redeem_config(terra: LocalTerra | LCDClien
     return resp;
   }
 
-  export async function get_state(terra: LocalTerra | LCDClient, manifesto_address: string) {
-    let query = { "state": {} };
+  export async function get_manifesto(terra: LocalTerra | LCDClient, manifesto_address: string, manifesto_id: number) {
+    let query = { "manifesto": {"manifesto_id":manifesto_id} };
+    let resp = await queryContract(terra, manifesto_address, query ); 
+    return resp;
+  }
+
+  export async function get_manifesto_version(terra: LocalTerra | LCDClient, manifesto_address: string, manifesto_id: number, version: number | null) {
+    let query = { "manifesto_version": {"manifesto_id":manifesto_id, "version":version} };
+    let resp = await queryContract(terra, manifesto_address, query ); 
+    return resp;
+  }
+
+  export async function get_state(terra: LocalTerra | LCDClient, manifesto_address: string, manifesto_id: number) {
+    let query = { "state": {"manifesto_id":manifesto_id} };
+    let resp = await queryContract(terra, manifesto_address, query ); 
+    return resp;
+  }
+
+  export async function get_signatures_by_date(terra: LocalTerra | LCDClient, manifesto_address: string, manifesto_id: number, date: string, start_after?: number, limit?: number) {
+    let query = { "signatures_by_date": {"manifesto_id":manifesto_id, "date":date, "start_after":start_after, "limit":limit} };
+    let resp = await queryContract(terra, manifesto_address, query ); 
+    return resp;
+  }
+
+  export async function get_signature_histogram(terra: LocalTerra | LCDClient, manifesto_address: string, manifesto_id: number, from: string, to: string) {
+    let query = { "signature_histogram": {"manifesto_id":manifesto_id, "from":from, "to":to} };
+    let resp = await queryContract(terra, manifesto_address, query ); 
+    return resp;
+  }
+
+  export async function is_signee(terra: LocalTerra | LCDClient, manifesto_address: string, manifesto_id: number, userAddress: string) {
+    let query = { "is_signee": {"manifesto_id":manifesto_id, "address":userAddress} };
+    let resp = await queryContract(terra, manifesto_address, query ); 
+    return resp;
+  }
+
+  export async function are_signees(terra: LocalTerra | LCDClient, manifesto_address: string, manifesto_id: number, addresses: string[]) {
+    let query = { "are_signees": {"manifesto_id":manifesto_id, "addresses":addresses} };
+    let resp = await queryContract(terra, manifesto_address, query ); 
+    return resp;
+  }
+
+  export async function get_state_at_height(terra: LocalTerra | LCDClient, manifesto_address: string, manifesto_id: number, height: number) {
+    let query = { "state_at_height": {"manifesto_id":manifesto_id, "height":height} };
+    let resp = await queryContract(terra, manifesto_address, query ); 
+    return resp;
+  }
+
+  export async function get_signature_at_height(terra: LocalTerra | LCDClient, manifesto_address: string, manifesto_id: number, userAddress: string, height: number) {
+    let query = { "signature_at_height": {"manifesto_id":manifesto_id, "signee":userAddress, "height":height} };
     let resp = await queryContract(terra, manifesto_address, query ); 
     return resp;
   }
 
-  export async function get_signature(terra: LocalTerra | LCDClient, manifesto_address: string, userAddress: string) {
-    let query = { "get_signature": {"signee":userAddress} };
+  export async function get_signature(terra: LocalTerra | LCDClient, manifesto_address: string, manifesto_id: number, userAddress: string) {
+    let query = { "get_signature": {"manifesto_id":manifesto_id, "signee":userAddress} };
     let resp = await queryContract(terra, manifesto_address, query ); 
     return resp;
   }
\ No newline at end of file
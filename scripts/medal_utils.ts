@@ -17,6 +17,7 @@ import { executeContract, queryContract} from "./helpers.js";
 // - Mint
 // - RedeemMedal
 // - UpdateMedalRedeemConfig
+// - UpdateRedemptionFee
 //------------------------------------------------------
 //------------------------------------------------------
 // ----------- Queries :: Function signatures ----------
@@ -28,6 +29,8 @@ import { executeContract, queryContract} from "./helpers.js";
 // - Tokens
 // - AllTokens
 // - Minter
+// - RedemptionFee
+// - Redemption
 //------------------------------------------------------
 
 
@@ -74,9 +77,9 @@ export async function mint_nft(terra: LocalTerra | LCDClient, wallet: Wallet, nf
     return resp;
 }
 
-export async function redeem_medal(terra: LocalTerra | LCDClient, wallet: Wallet, nft_address: string, token_id: string ) {
-    let _msg = { "redeem_medal": { "token_id":token_id } };
-    let resp = await executeContract(terra, wallet, nft_address, _msg ); 
+export async function redeem_medal(terra: LocalTerra | LCDClient, wallet: Wallet, nft_address: string, token_id: string, region?: string, funds?: string ) {
+    let _msg = { "redeem_medal": { "token_id":token_id, "region":region } };
+    let resp = await executeContract(terra, wallet, nft_address, _msg, funds ); 
     return resp;
 }
 
@@ -86,6 +89,32 @@ export async function update_medal_redeem_config(terra: LocalTerra | LCDClient,
     return resp;
 }
 
+export async function update_redemption_fee(terra: LocalTerra | LCDClient, wallet: Wallet, nft_address: string, recipient: string, regions: object[] ) {
+    let _msg = { "update_redemption_fee": { "recipient":recipient, "regions":regions } };
+    let resp = await executeContract(terra, wallet, nft_address, _msg ); 
+    return resp;
+}
+
+
+// Pause messages are the same for the manifesto, MEDAL and R-MEDAL contracts
+export async function update_guardian(terra: LocalTerra | LCDClient, wallet: Wallet, contract_address: string, guardian?: string ) {
+    let _msg = { "update_guardian": { "guardian":guardian } };
+    let resp = await executeContract(terra, wallet, contract_address, _msg ); 
+    return resp;
+}
+
+export async function pause(terra: LocalTerra | LCDClient, wallet: Wallet, contract_address: string, flags: string[] ) {
+    let _msg = { "pause": { "flags":flags } };
+    let resp = await executeContract(terra, wallet, contract_address, _msg ); 
+    return resp;
+}
+
+export async function unpause(terra: LocalTerra | LCDClient, wallet: Wallet, contract_address: string, flags: string[] ) {
+    let _msg = { "unpause": { "flags":flags } };
+    let resp = await executeContract(terra, wallet, contract_address, _msg ); 
+    return resp;
+}
+
 
 export async function get_owner(terra: LocalTerra | LCDClient, nft_address: string, token_id: string, include_expired: any) {
     let query = { "owner_of": { "token_id":token_id, "include_expired":include_expired } };
@@ -143,3 +172,20 @@ export async function get_minter(terra: LocalTerra | LCDClient, nft_address: str
     return resp;
 }
 
+export async function get_paused(terra: LocalTerra | LCDClient, contract_address: string) {
+    let query = { "paused": {  } };
+    let resp = await queryContract(terra, contract_address, query ); 
+    return resp;
+}
+
+export async function get_redemption_fee(terra: LocalTerra | LCDClient, nft_address: string) {
+    let query = { "redemption_fee": {  } };
+    let resp = await queryContract(terra, nft_address, query ); 
+    return resp;
+}
+
+export async function get_redemption(terra: LocalTerra | LCDClient, nft_address: string, token_id: string) {
+    let query = { "redemption": { "token_id":token_id } };
+    let resp = await queryContract(terra, nft_address, query ); 
+    return resp;
+}